@@ -1,5 +1,3 @@
-use std::sync::{Arc, Mutex};
-
 use egui::scroll_area::ScrollBarVisibility;
 use egui::{CentralPanel, Id, ViewportBuilder};
 use egui_dock::egui::{Ui, WidgetText};
@@ -30,10 +28,10 @@ struct Tab {
 enum TabKind {
     Table1,
     Example1 {
-        state: Arc<Mutex<ExampleWindowState>>,
+        state: ExampleWindowState,
     },
     ToolWindows {
-        state: Arc<Mutex<ExampleWindowState>>,
+        state: ExampleWindowState,
         salt: &'static str,
         scrollable: bool,
     },
@@ -49,7 +47,7 @@ fn default() -> Self {
         let mut tree = DockState::new(vec![Tab {
             name: "Scrollable tool windows in a tab",
             kind: TabKind::ToolWindows {
-                state: Arc::new(Mutex::new(ExampleWindowState::default())),
+                state: ExampleWindowState::default(),
                 salt: "tab 1",
                 scrollable: true,
             },
@@ -67,14 +65,14 @@ fn default() -> Self {
             .split_below(a, 0.7, vec![Tab {
                 name: "Example Controls",
                 kind: TabKind::Example1 {
-                    state: Arc::new(Mutex::new(ExampleWindowState::default())),
+                    state: ExampleWindowState::default(),
                 },
             }]);
         let _ = tree.add_window(vec![
             Tab {
                 name: "Tool windows in initially floating dock window",
                 kind: TabKind::ToolWindows {
-                    state: Arc::new(Mutex::new(ExampleWindowState::default())),
+                    state: ExampleWindowState::default(),
                     salt: "tab 4",
                     scrollable: false,
                 },
@@ -101,8 +99,7 @@ pub fn ui(&mut self, ui: &mut egui::Ui) {
             TabKind::Example1 {
                 state,
             } => {
-                let mut example_state = state.lock().unwrap();
-                shared::draw_example_window_contents_1(ui, &mut example_state);
+                shared::draw_example_window_contents_1(ui, state);
             }
             TabKind::ToolWindows {
                 state,
@@ -115,14 +112,12 @@ pub fn ui(&mut self, ui: &mut egui::Ui) {
                     .show(ui, |ui| {
                         ui.checkbox(scrollable, "Scrollable mode");
 
-                        // using a block to make sure the state guard is dropped
-                        {
-                            let mut example_state = state.lock().unwrap();
-                            shared::draw_example_window_contents_1(ui, &mut example_state);
-                        }
+                        shared::draw_example_window_contents_1(ui, state);
 
                         shared::draw_table(ui, "table_2");
+                        // an explicit id keeps the tool windows' stacking order when the tab is moved to another node
                         ToolWindows::new()
+                            .id(Id::new("tool_windows").with(*salt))
                             .scrollable(*scrollable)
                             .windows(ui, |builder| {
                                 builder
@@ -139,13 +134,8 @@ pub fn ui(&mut self, ui: &mut egui::Ui) {
                                     .default_size([400.0, 300.0])
                                     .show(
                                         "Example table 2 (drag or collapse me) - very very long title".to_string(),
-                                        {
-                                            let example_state_arc = state.clone();
-
-                                            move |ui| {
-                                                let mut example_state = example_state_arc.lock().unwrap();
-                                                shared::draw_example_window_contents_1(ui, &mut example_state);
-                                            }
+                                        |ui| {
+                                            shared::draw_example_window_contents_1(ui, state);
                                         },
                                     );
                             });
@@ -165,7 +155,7 @@ fn id(&mut self, tab: &mut Self::Tab) -> Id {
     }
 
     fn title(&mut self, tab: &mut Self::Tab) -> WidgetText {
-        (&*tab.name).into()
+        tab.name.into()
     }
 
     fn ui(&mut self, ui: &mut Ui, tab: &mut Self::Tab) {
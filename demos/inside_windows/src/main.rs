@@ -1,5 +1,3 @@
-use std::sync::{Arc, Mutex};
-
 use eframe::emath::{Rect, Vec2};
 use egui::scroll_area::ScrollBarVisibility;
 use egui::{CentralPanel, Context, Id, ViewportBuilder, Window};
@@ -21,18 +19,10 @@ fn main() -> eframe::Result<()> {
     )
 }
 
+#[derive(Default)]
 struct MyApp {
     inspection: bool,
-    example_state: Arc<Mutex<ExampleWindowState>>,
-}
-
-impl Default for MyApp {
-    fn default() -> Self {
-        Self {
-            inspection: false,
-            example_state: Arc::new(Mutex::new(ExampleWindowState::default())),
-        }
-    }
+    example_state: ExampleWindowState,
 }
 
 enum ExampleWindowKind {
@@ -70,8 +60,7 @@ fn create_document_window(
                         shared::draw_table(ui, "table_1");
                     }
                     ExampleWindowKind::Example1 => {
-                        let mut example_state = self.example_state.lock().unwrap();
-                        shared::draw_example_window_contents_1(ui, &mut example_state);
+                        shared::draw_example_window_contents_1(ui, &mut self.example_state);
                     }
                     ExampleWindowKind::ToolWindows1 => {
                         egui::ScrollArea::both()
@@ -93,13 +82,8 @@ fn create_document_window(
                                         .default_size([400.0, 300.0])
                                         .show(
                                             "Example controls (drag or collapse me) - very very long title".to_string(),
-                                            {
-                                                let example_state_arc = self.example_state.clone();
-
-                                                move |ui| {
-                                                    let mut example_state = example_state_arc.lock().unwrap();
-                                                    shared::draw_example_window_contents_1(ui, &mut example_state);
-                                                }
+                                            |ui| {
+                                                shared::draw_example_window_contents_1(ui, &mut self.example_state);
                                             },
                                         );
                                 });
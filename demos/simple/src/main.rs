@@ -1,8 +1,6 @@
-use std::sync::{Arc, Mutex};
-
 use egui::scroll_area::ScrollBarVisibility;
 use egui::{CentralPanel, Id, Style, ViewportBuilder};
-use egui_tool_windows::{ToolWindowAction, ToolWindows};
+use egui_tool_windows::{KeyboardNavigation, Snapping, TitleBarDoubleClick, ToolWindows, ToolWindowsLayout};
 use shared::ExampleWindowState;
 
 fn main() -> eframe::Result<()> {
@@ -20,22 +18,12 @@ fn main() -> eframe::Result<()> {
     )
 }
 
+#[derive(Default)]
 struct MyApp {
     inspection: bool,
-    example_state: Arc<Mutex<ExampleWindowState>>,
-    show_tool_window_2: bool,
+    example_state: ExampleWindowState,
     scrollable: bool,
-}
-
-impl Default for MyApp {
-    fn default() -> Self {
-        Self {
-            inspection: false,
-            example_state: Arc::new(Mutex::new(ExampleWindowState::default())),
-            show_tool_window_2: true,
-            scrollable: false,
-        }
-    }
+    saved_layout: Option<ToolWindowsLayout>,
 }
 
 impl eframe::App for MyApp {
@@ -52,15 +40,28 @@ fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
                 egui::Frame::group(&Style::default())
                     .outer_margin(40.0)
                     .show(ui, |ui| {
-                        if ui
-                            .selectable_label(self.show_tool_window_2, "Show tool window 2")
-                            .clicked()
-                        {
-                            self.show_tool_window_2 = !self.show_tool_window_2;
-                        }
+                        let tool_windows_id = Id::new("tool_windows");
+                        ui.menu_button("Windows", |ui| {
+                            ToolWindows::memory(ui.ctx(), tool_windows_id).windows_menu_ui(ui);
+                        });
 
                         ui.checkbox(&mut self.scrollable, "Scrollable");
 
+                        ui.horizontal(|ui| {
+                            let memory = ToolWindows::memory(ui.ctx(), tool_windows_id);
+                            if ui.button("Reset layout").clicked() {
+                                memory.reset_all_to_default();
+                            }
+                            if ui.button("Save layout").clicked() {
+                                self.saved_layout = Some(memory.layout("Saved"));
+                            }
+                            if let Some(layout) = &self.saved_layout
+                                && ui.button("Restore layout").clicked()
+                            {
+                                memory.apply_layout(layout);
+                            }
+                        });
+
                         egui::ScrollArea::both()
                             .auto_shrink([false, false])
                             .scroll_bar_visibility(ScrollBarVisibility::AlwaysVisible)
@@ -71,11 +72,19 @@ fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
 
                                 let tool_window_1_id = Id::new("table_tool_window_1");
                                 let tool_window_2_id = Id::new("table_tool_window_2");
-                                let all_actions = ToolWindows::new()
+                                let response = ToolWindows::new()
+                                    .id(tool_windows_id)
                                     .scrollable(self.scrollable)
+                                    .snapping(Snapping::default())
+                                    .keyboard_navigation(KeyboardNavigation::default())
+                                    .docking(true)
+                                    .auto_close(true)
                                     .windows(ui, |builder| {
                                         builder
                                             .add_window(tool_window_1_id)
+                                            .minimizable(true)
+                                            .maximizable(true)
+                                            .icon("📋")
                                             .default_pos([50.0, 50.0])
                                             .default_size([400.0, 300.0])
                                             .show("Example table 1 (drag or collapse me)".to_string(), |ui| {
@@ -83,48 +92,33 @@ fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
                                                 shared::draw_table(ui, "table_1");
                                             });
 
-                                        if self.show_tool_window_2 {
-                                            builder
-                                                .add_window(tool_window_2_id)
-                                                .closable(true)
-                                                .default_pos([100.0, 100.0])
-                                                .default_size([400.0, 300.0])
-                                                .titlebar_content(|ui| {
-                                                    ui.label("Custom UI");
-                                                })
-                                                .show(
-                                                    "Example window 2 (drag or collapse me) - very very long title"
-                                                        .to_string(),
-                                                    {
-                                                        let example_state_arc = self.example_state.clone();
-
-                                                        move |ui| {
-                                                            ui.set_min_height(50.0);
-
-                                                            let mut example_state = example_state_arc.lock().unwrap();
-                                                            shared::draw_example_window_contents_1(
-                                                                ui,
-                                                                &mut example_state,
-                                                            );
-                                                        }
-                                                    },
-                                                );
-                                        }
+                                        builder
+                                            .add_window(tool_window_2_id)
+                                            .closable(true)
+                                            .default_pos([100.0, 100.0])
+                                            .default_size([400.0, 300.0])
+                                            .min_size([250.0, 150.0])
+                                            .title_bar_double_click(TitleBarDoubleClick::AutoSize)
+                                            .vscroll(true)
+                                            .titlebar_content(|ui| {
+                                                ui.label("Custom UI");
+                                            })
+                                            .show(
+                                                "Example window 2 (drag or collapse me) - very very long title"
+                                                    .to_string(),
+                                                |ui| {
+                                                    ui.set_min_height(50.0);
+
+                                                    shared::draw_example_window_contents_1(
+                                                        ui,
+                                                        &mut self.example_state,
+                                                    );
+                                                },
+                                            );
                                     });
 
-                                if !all_actions.is_empty() {
-                                    println!("all_actions: {:?}", all_actions);
-                                }
-                                for (id, window_actions) in all_actions {
-                                    if id.eq(&tool_window_2_id) {
-                                        for action in window_actions {
-                                            match action {
-                                                ToolWindowAction::CloseRequested => {
-                                                    self.show_tool_window_2 = false;
-                                                }
-                                            }
-                                        }
-                                    }
+                                for (id, action) in response.actions() {
+                                    println!("action: {:?}, id: {:?}", action, id);
                                 }
                             });
                     });
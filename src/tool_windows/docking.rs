@@ -0,0 +1,264 @@
+use egui::{Id, Pos2, Rect, Vec2, pos2};
+use log::trace;
+
+use crate::tool_windows::ToolWindowsState;
+
+/// How close, in points, the pointer has to be to a container edge to dock a window being dragged
+/// to it.
+pub(crate) const DOCK_EDGE_THRESHOLD: f32 = 16.0;
+
+/// A container edge that a window can be docked to, see [`crate::ToolWindows::docking`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DockSide {
+    /// The edge of `viewport` that `pointer` is within `threshold` of, if any.
+    pub(crate) fn near(viewport: Rect, pointer: Pos2, threshold: f32) -> Option<Self> {
+        if !viewport.contains(pointer) {
+            return None;
+        }
+
+        [
+            (Self::Left, pointer.x - viewport.left()),
+            (Self::Right, viewport.right() - pointer.x),
+            (Self::Top, pointer.y - viewport.top()),
+            (Self::Bottom, viewport.bottom() - pointer.y),
+        ]
+        .into_iter()
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(side, _)| side)
+    }
+
+    /// How much of the container a window of this (outer) size takes up when docked to this side.
+    pub(crate) fn thickness(self, size: Vec2) -> f32 {
+        match self {
+            Self::Left | Self::Right => size.x,
+            Self::Top | Self::Bottom => size.y,
+        }
+    }
+
+    /// Split a strip of `thickness` off this side of `available`, returns the strip and what's left.
+    pub(crate) fn split(self, available: Rect, thickness: f32) -> (Rect, Rect) {
+        let (min, max) = (available.min, available.max);
+        match self {
+            Self::Left => {
+                let x = (min.x + thickness).min(max.x);
+                (
+                    Rect::from_min_max(min, pos2(x, max.y)),
+                    Rect::from_min_max(pos2(x, min.y), max),
+                )
+            }
+            Self::Right => {
+                let x = (max.x - thickness).max(min.x);
+                (
+                    Rect::from_min_max(pos2(x, min.y), max),
+                    Rect::from_min_max(min, pos2(x, max.y)),
+                )
+            }
+            Self::Top => {
+                let y = (min.y + thickness).min(max.y);
+                (
+                    Rect::from_min_max(min, pos2(max.x, y)),
+                    Rect::from_min_max(pos2(min.x, y), max),
+                )
+            }
+            Self::Bottom => {
+                let y = (max.y - thickness).max(min.y);
+                (
+                    Rect::from_min_max(pos2(min.x, y), max),
+                    Rect::from_min_max(min, pos2(max.x, y)),
+                )
+            }
+        }
+    }
+
+    /// The name of the only edge a window docked to this side can be resized from, the one facing
+    /// the rest of the container.
+    pub(crate) fn resize_edge(self) -> &'static str {
+        match self {
+            Self::Left => "right",
+            Self::Right => "left",
+            Self::Top => "bottom",
+            Self::Bottom => "top",
+        }
+    }
+}
+
+/// A window docked to a container edge, see `ToolWindowsState::docked`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct DockedWindow {
+    pub(crate) id: Id,
+    pub(crate) side: DockSide,
+}
+
+/// Where a docked window goes this frame, in screen coordinates.
+#[derive(Clone, Copy)]
+pub(crate) struct DockSlot {
+    pub(crate) side: DockSide,
+    /// What's left of the container's visible area once the windows docked before this one have
+    /// taken their strips.
+    pub(crate) available: Rect,
+}
+
+impl DockSlot {
+    /// The strip a window of this (outer) size takes up.
+    pub(crate) fn strip(&self, size: Vec2) -> Rect {
+        self.side
+            .split(self.available, self.side.thickness(size))
+            .0
+    }
+}
+
+impl ToolWindowsState {
+    /// Dock the window to `side`, inside any windows already docked to the container.
+    pub(crate) fn dock(&mut self, id: Id, side: DockSide) {
+        self.docked
+            .retain(|docked| docked.id != id);
+        self.docked.push(DockedWindow {
+            id,
+            side,
+        });
+        trace!("docked window. id: {:?}, side: {:?}", id, side);
+    }
+
+    /// Returns `true` if the window was docked.
+    pub(crate) fn undock(&mut self, id: Id) -> bool {
+        let count = self.docked.len();
+        self.docked
+            .retain(|docked| docked.id != id);
+        let undocked = self.docked.len() != count;
+        if undocked {
+            trace!("undocked window. id: {:?}", id);
+        }
+        undocked
+    }
+
+    /// Hand the window's place in the docked strips over to another window, e.g. when a docked
+    /// tab group's selected tab changes.
+    pub(crate) fn replace_docked(&mut self, from: Id, to: Id) {
+        if let Some(docked) = self
+            .docked
+            .iter_mut()
+            .find(|docked| docked.id == from)
+        {
+            docked.id = to;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Rect, pos2, vec2};
+
+    use super::DockSide;
+
+    fn viewport() -> Rect {
+        Rect::from_min_max(pos2(100.0, 50.0), pos2(900.0, 650.0))
+    }
+
+    #[test]
+    fn split_each_side() {
+        let available = viewport();
+
+        assert_eq!(
+            DockSide::Left.split(available, 200.0),
+            (
+                Rect::from_min_max(pos2(100.0, 50.0), pos2(300.0, 650.0)),
+                Rect::from_min_max(pos2(300.0, 50.0), pos2(900.0, 650.0)),
+            )
+        );
+        assert_eq!(
+            DockSide::Right.split(available, 200.0),
+            (
+                Rect::from_min_max(pos2(700.0, 50.0), pos2(900.0, 650.0)),
+                Rect::from_min_max(pos2(100.0, 50.0), pos2(700.0, 650.0)),
+            )
+        );
+        assert_eq!(
+            DockSide::Top.split(available, 150.0),
+            (
+                Rect::from_min_max(pos2(100.0, 50.0), pos2(900.0, 200.0)),
+                Rect::from_min_max(pos2(100.0, 200.0), pos2(900.0, 650.0)),
+            )
+        );
+        assert_eq!(
+            DockSide::Bottom.split(available, 150.0),
+            (
+                Rect::from_min_max(pos2(100.0, 500.0), pos2(900.0, 650.0)),
+                Rect::from_min_max(pos2(100.0, 50.0), pos2(900.0, 500.0)),
+            )
+        );
+    }
+
+    #[test]
+    fn split_thicker_than_available_takes_everything() {
+        let available = viewport();
+
+        for side in [DockSide::Left, DockSide::Right, DockSide::Top, DockSide::Bottom] {
+            let (strip, rest) = side.split(available, 1000.0);
+            assert_eq!(strip, available, "{side:?}");
+            assert_eq!(rest.area(), 0.0, "{side:?}");
+        }
+    }
+
+    #[test]
+    fn thickness_is_the_size_across_the_side() {
+        let size = vec2(200.0, 150.0);
+
+        assert_eq!(DockSide::Left.thickness(size), 200.0);
+        assert_eq!(DockSide::Right.thickness(size), 200.0);
+        assert_eq!(DockSide::Top.thickness(size), 150.0);
+        assert_eq!(DockSide::Bottom.thickness(size), 150.0);
+    }
+
+    #[test]
+    fn near_at_the_threshold() {
+        let viewport = viewport();
+        let threshold = 16.0;
+
+        assert_eq!(
+            DockSide::near(viewport, pos2(116.0, 300.0), threshold),
+            Some(DockSide::Left)
+        );
+        assert_eq!(DockSide::near(viewport, pos2(116.5, 300.0), threshold), None);
+        assert_eq!(
+            DockSide::near(viewport, pos2(884.0, 300.0), threshold),
+            Some(DockSide::Right)
+        );
+        assert_eq!(DockSide::near(viewport, pos2(883.5, 300.0), threshold), None);
+        assert_eq!(
+            DockSide::near(viewport, pos2(500.0, 66.0), threshold),
+            Some(DockSide::Top)
+        );
+        assert_eq!(DockSide::near(viewport, pos2(500.0, 66.5), threshold), None);
+        assert_eq!(
+            DockSide::near(viewport, pos2(500.0, 634.0), threshold),
+            Some(DockSide::Bottom)
+        );
+        assert_eq!(DockSide::near(viewport, pos2(500.0, 633.5), threshold), None);
+    }
+
+    #[test]
+    fn near_picks_the_closest_edge_in_a_corner() {
+        let viewport = viewport();
+
+        assert_eq!(DockSide::near(viewport, pos2(110.0, 55.0), 16.0), Some(DockSide::Top));
+        assert_eq!(
+            DockSide::near(viewport, pos2(895.0, 640.0), 16.0),
+            Some(DockSide::Right)
+        );
+    }
+
+    #[test]
+    fn near_ignores_a_pointer_outside_the_viewport() {
+        assert_eq!(DockSide::near(viewport(), pos2(95.0, 300.0), 16.0), None);
+    }
+}
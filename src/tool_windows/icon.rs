@@ -0,0 +1,61 @@
+use egui::{Atom, AtomLayout, Image, ImageSource, TextStyle, TextWrapMode, Ui, Vec2, WidgetText};
+
+/// A small picture shown before a window's title, see [`crate::ToolWindowInstanceBuilder::icon`].
+#[derive(Clone, Debug)]
+pub enum ToolWindowIcon {
+    /// A piece of text, e.g. an emoji, shown like the title.
+    Glyph(String),
+    /// An image, scaled to the height of the title's text.
+    Image(ImageSource<'static>),
+}
+
+impl From<&str> for ToolWindowIcon {
+    fn from(glyph: &str) -> Self {
+        Self::Glyph(glyph.to_string())
+    }
+}
+
+impl From<String> for ToolWindowIcon {
+    fn from(glyph: String) -> Self {
+        Self::Glyph(glyph)
+    }
+}
+
+impl From<ImageSource<'static>> for ToolWindowIcon {
+    fn from(image: ImageSource<'static>) -> Self {
+        Self::Image(image)
+    }
+}
+
+impl ToolWindowIcon {
+    /// The icon, for adding to a button or label alongside the title.
+    pub(crate) fn atom(&self, ui: &Ui) -> Atom<'static> {
+        match self {
+            Self::Glyph(glyph) => glyph.clone().into(),
+            Self::Image(image) => Image::new(image.clone())
+                .fit_to_exact_size(Vec2::splat(Self::image_size(ui)))
+                .into(),
+        }
+    }
+
+    /// How much room the icon takes up in a button.
+    pub(crate) fn width(&self, ui: &Ui) -> f32 {
+        match self {
+            Self::Glyph(glyph) => {
+                WidgetText::from(glyph.as_str())
+                    .into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, TextStyle::Button)
+                    .size()
+                    .x
+            }
+            Self::Image(_) => Self::image_size(ui),
+        }
+    }
+
+    fn image_size(ui: &Ui) -> f32 {
+        ui.text_style_height(&TextStyle::Body)
+    }
+
+    pub(crate) fn show(&self, ui: &mut Ui) {
+        AtomLayout::new(self.atom(ui)).show(ui);
+    }
+}
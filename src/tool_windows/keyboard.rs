@@ -0,0 +1,372 @@
+use std::ops::Range;
+
+use egui::{
+    Align2, Atoms, Button, Context, Event, FocusDirection, Frame, Id, Key, KeyboardShortcut, LayerId, Modifiers, Order,
+    Popup, Rect, TextWrapMode, Ui, Vec2, WidgetText, vec2,
+};
+use log::trace;
+
+use crate::tool_windows::{ToolWindowAction, ToolWindowIcon, ToolWindowsState};
+
+/// Keyboard shortcuts for moving between a container's windows, see
+/// [`crate::ToolWindows::keyboard_navigation`].
+///
+/// The shortcuts act on every container that has keyboard navigation enabled, so only enable it on
+/// one container at a time.
+#[derive(Clone, Debug)]
+pub struct KeyboardNavigation {
+    /// Ctrl+Tab and Ctrl+Shift+Tab bring the next and previous window to the front, in
+    /// most-recently-used order. While Ctrl is held the order stays the same, so pressing Tab
+    /// repeatedly visits every window, and releasing Ctrl leaves the chosen window on top.
+    pub switch_windows: bool,
+
+    /// While Ctrl is held after Ctrl+Tab, list the windows in the middle of the container, with the
+    /// chosen window highlighted. Escape goes back to the window that was on top before.
+    pub switcher: bool,
+
+    /// Tab moves keyboard focus to the first widget in the topmost window's content, unless it's
+    /// already in there, in which case Tab moves between the content's widgets as usual.
+    pub focus_content: bool,
+
+    /// Escape reports `ToolWindowAction::CloseRequested` for the topmost window if it's `closable`.
+    /// Not while a widget has keyboard focus or a popup is open, since Escape is used to leave
+    /// those first.
+    pub escape_closes: bool,
+
+    /// Start moving and resizing the topmost window with the keyboard, see
+    /// [`crate::ToolWindowsMemory::move_resize_with_keyboard`].
+    pub move_resize: Option<KeyboardShortcut>,
+}
+
+impl Default for KeyboardNavigation {
+    fn default() -> Self {
+        Self {
+            switch_windows: true,
+            switcher: true,
+            focus_content: true,
+            escape_closes: true,
+            move_resize: Some(KeyboardShortcut::new(Modifiers::COMMAND, Key::F7)),
+        }
+    }
+}
+
+/// Keyboard navigation state, which only lasts as long as the app runs.
+#[derive(Clone, Default)]
+pub(crate) struct KeyboardState {
+    /// The Ctrl+Tab cycle in progress, until Ctrl is released.
+    switching: Option<WindowSwitch>,
+
+    /// The focusable widgets in the topmost window's content last frame, in the order Tab moves
+    /// through them.
+    content_widgets: Vec<Id>,
+
+    /// Whether a widget had keyboard focus at the end of the last frame. egui drops the focus as
+    /// soon as Escape is pressed, so this is what tells leaving a text field with Escape apart from
+    /// closing a window with it.
+    had_focus: bool,
+}
+
+#[derive(Clone)]
+struct WindowSwitch {
+    /// The windows, most recently used first, as of when Ctrl+Tab was first pressed.
+    order: Vec<Id>,
+    selected: usize,
+}
+
+/// A window that can be switched to or closed from the keyboard.
+pub(crate) struct SwitchableWindow {
+    pub(crate) id: Id,
+    pub(crate) title: WidgetText,
+    pub(crate) icon: Option<ToolWindowIcon>,
+    pub(crate) closable: bool,
+    /// Being moved and resized with the keyboard, which has the arrow keys, Enter and Escape to
+    /// itself.
+    pub(crate) moving: bool,
+}
+
+/// What the keyboard did this frame, before any windows were shown.
+#[derive(Default)]
+pub(crate) struct KeyboardInput {
+    pub(crate) actions: Vec<(Id, ToolWindowAction)>,
+    /// Tab was pressed to move keyboard focus into the topmost window's content, which is done
+    /// once the windows have been shown, see [`end_frame`].
+    pub(crate) focus_content: bool,
+    /// The window to start moving and resizing with the keyboard.
+    pub(crate) move_resize: Option<Id>,
+}
+
+/// Handle the container's keyboard shortcuts. `windows` are the windows that are shown, most
+/// recently used, i.e. topmost, first.
+pub(crate) fn handle_input(
+    ui: &Ui,
+    switcher_id: Id,
+    navigation: &KeyboardNavigation,
+    state: &mut ToolWindowsState,
+    windows: &[SwitchableWindow],
+) -> KeyboardInput {
+    let ctx = ui.ctx();
+    let mut input = KeyboardInput::default();
+    let mut raise = None;
+
+    if navigation.switch_windows {
+        // Ctrl+Shift+Tab first, since Ctrl+Tab would match it too.
+        let step = ctx.input_mut(|i| {
+            if i.consume_key(Modifiers::CTRL | Modifiers::SHIFT, Key::Tab) {
+                Some(-1)
+            } else if i.consume_key(Modifiers::CTRL, Key::Tab) {
+                Some(1)
+            } else {
+                None
+            }
+        });
+
+        if let Some(step) = step {
+            let switch = state
+                .keyboard
+                .switching
+                .get_or_insert_with(|| WindowSwitch {
+                    order: windows
+                        .iter()
+                        .map(|window| window.id)
+                        .collect(),
+                    selected: 0,
+                });
+            // Windows may have been closed since the cycle started.
+            switch.order.retain(|id| {
+                windows
+                    .iter()
+                    .any(|window| window.id == *id)
+            });
+            if !switch.order.is_empty() {
+                switch.selected = (switch.selected as isize + step).rem_euclid(switch.order.len() as isize) as usize;
+                raise = Some(switch.order[switch.selected]);
+            }
+        }
+
+        if let Some(switch) = &state.keyboard.switching {
+            if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+                trace!("cancelling window switch");
+                raise = switch.order.first().copied();
+                state.keyboard.switching = None;
+            } else if !ctx.input(|i| i.modifiers.ctrl) {
+                state.keyboard.switching = None;
+            } else if navigation.switcher
+                && let Some(clicked) = show_switcher(ctx, switcher_id, ui.clip_rect(), windows, switch)
+            {
+                raise = Some(clicked);
+                state.keyboard.switching = None;
+            }
+        }
+    }
+
+    if let Some(id) = raise
+        && !state.is_topmost(id)
+    {
+        trace!("switching to window: {:?}", id);
+        state.bring_to_front(id);
+        input
+            .actions
+            .push((id, ToolWindowAction::BroughtToFront));
+    }
+
+    let topmost = windows
+        .first()
+        .filter(|topmost| state.is_topmost(topmost.id));
+    if topmost.is_some_and(|topmost| topmost.moving) {
+        return input;
+    }
+
+    if let Some(shortcut) = &navigation.move_resize
+        && let Some(topmost) = topmost
+        && ctx.input_mut(|i| i.consume_shortcut(shortcut))
+    {
+        input.move_resize = Some(topmost.id);
+    }
+
+    if navigation.escape_closes
+        && !state.keyboard.had_focus
+        && let Some(topmost) = topmost
+        && topmost.closable
+        && !Popup::is_any_open(ctx)
+        && ctx.input_mut(|i| i.modifiers.is_none() && i.consume_key(Modifiers::NONE, Key::Escape))
+    {
+        trace!("closing window: {:?}", topmost.id);
+        input
+            .actions
+            .push((topmost.id, ToolWindowAction::CloseRequested));
+    }
+
+    if navigation.focus_content
+        && !state
+            .keyboard
+            .content_widgets
+            .is_empty()
+        && ctx
+            .memory(|m| m.focused())
+            .is_none_or(|focused| {
+                !state
+                    .keyboard
+                    .content_widgets
+                    .contains(&focused)
+            })
+        // Not Shift+Tab, which `consume_key` would match too.
+        && ctx.input_mut(|i| i.modifiers.is_none() && i.consume_key(Modifiers::NONE, Key::Tab))
+    {
+        // Stop egui moving the focus itself, to whatever widget comes next.
+        ctx.memory_mut(|m| m.move_focus(FocusDirection::None));
+        input.focus_content = true;
+    }
+
+    input
+}
+
+/// Remember what [`handle_input`] needs to know next frame, and focus the topmost window's content
+/// if Tab asked for it. `content_widgets` are the focusable widgets in the topmost window's content.
+pub(crate) fn end_frame(ctx: &Context, state: &mut ToolWindowsState, input: &KeyboardInput, content_widgets: Vec<Id>) {
+    // Done now, rather than when Tab was handled, so no widget shown since can take the focus back.
+    if input.focus_content
+        && let Some(first) = content_widgets.first()
+    {
+        ctx.memory_mut(|m| m.request_focus(*first));
+    }
+
+    state.keyboard.content_widgets = content_widgets;
+    state.keyboard.had_focus = ctx.memory(|m| m.focused().is_some());
+}
+
+/// The number of widgets on `layer_id` so far this frame, so the widgets a window adds can be told
+/// apart from the rest, see [`focusable_widgets`].
+pub(crate) fn widget_count(ctx: &Context, layer_id: LayerId) -> usize {
+    ctx.viewport(|viewport| {
+        viewport
+            .this_pass
+            .widgets
+            .get_layer(layer_id)
+            .count()
+    })
+}
+
+/// The focusable widgets added to `layer_id` in `range` this frame that are inside `content_rect`,
+/// i.e. in a window's content rather than its title bar or frame.
+pub(crate) fn focusable_widgets(ctx: &Context, layer_id: LayerId, range: Range<usize>, content_rect: Rect) -> Vec<Id> {
+    ctx.viewport(|viewport| {
+        viewport
+            .this_pass
+            .widgets
+            .get_layer(layer_id)
+            .skip(range.start)
+            .take(range.len())
+            .filter(|widget| widget.enabled && widget.sense.is_focusable() && content_rect.contains_rect(widget.rect))
+            .map(|widget| widget.id)
+            .collect()
+    })
+}
+
+/// The list of windows shown while switching, returns the window that was clicked, if any.
+fn show_switcher(
+    ctx: &Context,
+    id: Id,
+    container_rect: Rect,
+    windows: &[SwitchableWindow],
+    switch: &WindowSwitch,
+) -> Option<Id> {
+    egui::Area::new(id)
+        .order(Order::Foreground)
+        .pivot(Align2::CENTER_CENTER)
+        .fixed_pos(container_rect.center())
+        .show(ctx, |ui| {
+            Frame::popup(ui.style())
+                .show(ui, |ui| {
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+
+                    let mut clicked = None;
+                    for (index, id) in switch.order.iter().enumerate() {
+                        let Some(window) = windows
+                            .iter()
+                            .find(|window| window.id == *id)
+                        else {
+                            continue;
+                        };
+                        let mut atoms = Atoms::new(window.title.clone());
+                        if let Some(icon) = &window.icon {
+                            atoms.push_left(icon.atom(ui));
+                        }
+                        let response = ui
+                            .push_id(id, |ui| ui.add(Button::selectable(index == switch.selected, atoms)))
+                            .inner;
+                        if response.clicked() {
+                            clicked = Some(*id);
+                        }
+                    }
+                    clicked
+                })
+                .inner
+        })
+        .inner
+}
+
+/// How far, in points, each press of an arrow key moves or resizes a window, and with Ctrl held.
+const MOVE_RESIZE_STEP: f32 = 1.0;
+const MOVE_RESIZE_LARGE_STEP: f32 = 10.0;
+
+/// The keys pressed this frame while a window is being moved and resized with the keyboard.
+#[derive(Default)]
+pub(crate) struct MoveResizeInput {
+    pub(crate) movement: Vec2,
+    pub(crate) resize: Vec2,
+    /// Enter was pressed.
+    pub(crate) commit: bool,
+    /// Escape was pressed.
+    pub(crate) cancel: bool,
+}
+
+/// Take the arrow keys, Enter and Escape out of this frame's input, so the window's content doesn't
+/// act on them as well.
+pub(crate) fn move_resize_input(ctx: &Context) -> MoveResizeInput {
+    let mut input = MoveResizeInput::default();
+    ctx.input_mut(|i| {
+        i.events.retain(|event| {
+            let Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } = event
+            else {
+                return true;
+            };
+            let direction = match key {
+                Key::ArrowLeft => vec2(-1.0, 0.0),
+                Key::ArrowRight => vec2(1.0, 0.0),
+                Key::ArrowUp => vec2(0.0, -1.0),
+                Key::ArrowDown => vec2(0.0, 1.0),
+                Key::Enter => {
+                    input.commit = true;
+                    return false;
+                }
+                Key::Escape => {
+                    input.cancel = true;
+                    return false;
+                }
+                _ => return true,
+            };
+            let step = if modifiers.command {
+                MOVE_RESIZE_LARGE_STEP
+            } else {
+                MOVE_RESIZE_STEP
+            };
+            if modifiers.shift {
+                input.resize += direction * step;
+            } else {
+                input.movement += direction * step;
+            }
+            false
+        });
+    });
+
+    if input.movement != Vec2::ZERO || input.resize != Vec2::ZERO {
+        // Otherwise the arrow keys would also move the keyboard focus.
+        ctx.memory_mut(|m| m.move_focus(FocusDirection::None));
+    }
+    input
+}
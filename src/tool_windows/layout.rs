@@ -0,0 +1,105 @@
+use egui::{Id, Pos2, Vec2};
+
+/// A snapshot of the layout of the windows in a container, see
+/// [`crate::ToolWindowsMemory::layout`] and [`crate::ToolWindowsMemory::apply_layout`].
+///
+/// With the `persistence` feature enabled this can be serialized using `serde`, e.g. as JSON or
+/// RON, so that an application can save named workspaces and switch between them at runtime.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToolWindowsLayout {
+    /// A name for the layout, e.g. "Setup" or "Calibration", for the application's own use.
+    pub name: String,
+
+    /// In rendering order, i.e. the LAST one is on TOP.
+    pub windows: Vec<ToolWindowLayout>,
+}
+
+/// The layout of a single window, see [`ToolWindowsLayout`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToolWindowLayout {
+    pub id: Id,
+
+    /// Relative to the container's top-left corner.
+    pub position: Pos2,
+
+    pub size: Vec2,
+
+    pub collapsed: bool,
+
+    /// Layouts saved before this was added don't have it, and load as not minimized.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    pub minimized: bool,
+
+    /// `position` and `size` are where the window goes when it's restored.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    pub maximized: bool,
+}
+
+impl ToolWindowsLayout {
+    pub fn window(&self, id: Id) -> Option<&ToolWindowLayout> {
+        self.windows
+            .iter()
+            .find(|window| window.id == id)
+    }
+}
+
+#[cfg(all(test, feature = "persistence"))]
+mod tests {
+    use egui::{Id, pos2, vec2};
+
+    use super::{ToolWindowLayout, ToolWindowsLayout};
+
+    #[test]
+    fn round_trip_as_json() {
+        let layout = ToolWindowsLayout {
+            name: "Calibration".to_owned(),
+            windows: vec![
+                ToolWindowLayout {
+                    id: Id::new("camera"),
+                    position: pos2(10.0, 20.0),
+                    size: vec2(300.0, 200.0),
+                    collapsed: true,
+                    minimized: false,
+                    maximized: false,
+                },
+                ToolWindowLayout {
+                    id: Id::new("settings"),
+                    position: pos2(400.0, 20.0),
+                    size: vec2(250.0, 400.0),
+                    collapsed: false,
+                    minimized: true,
+                    maximized: true,
+                },
+            ],
+        };
+
+        let stored = serde_json::to_string(&layout).unwrap();
+        let loaded: ToolWindowsLayout = serde_json::from_str(&stored).unwrap();
+
+        assert_eq!(loaded, layout);
+    }
+
+    #[test]
+    fn load_json_without_minimized_and_maximized() {
+        let stored = serde_json::to_value(ToolWindowLayout {
+            id: Id::new("camera"),
+            position: pos2(10.0, 20.0),
+            size: vec2(300.0, 200.0),
+            collapsed: true,
+            minimized: true,
+            maximized: true,
+        })
+        .unwrap();
+        let mut stored = stored.as_object().unwrap().clone();
+        stored.remove("minimized");
+        stored.remove("maximized");
+
+        let loaded: ToolWindowLayout = serde_json::from_value(stored.into()).unwrap();
+
+        assert!(loaded.collapsed);
+        assert!(!loaded.minimized);
+        assert!(!loaded.maximized);
+    }
+}
@@ -0,0 +1,283 @@
+use egui::{Atoms, Checkbox, Context, Id, Pos2, Ui, Vec2};
+use log::trace;
+
+use crate::tool_windows::{
+    KnownToolWindow, ToolWindow, ToolWindowLayout, ToolWindowState, ToolWindows, ToolWindowsLayout,
+    ToolWindowsStatePersistence,
+};
+
+/// A handle to the stored state of the windows in a container, see [`ToolWindows::memory`].
+///
+/// Changes are written straight to egui's memory and take effect the next time the container's
+/// windows are shown, which is the same frame if this is used before [`ToolWindows::windows`].
+///
+/// A window's state only exists once it's been shown at least once; until then changes to it are
+/// ignored, and its `default_pos`/`default_size` apply as usual.
+///
+/// The windows in a tab group share the position, size and collapsed, minimized and maximized state
+/// of the group's selected tab, so changes to the other tabs' windows are overwritten the next time
+/// the group is shown.
+pub struct ToolWindowsMemory {
+    ctx: Context,
+    container_id: Id,
+}
+
+impl ToolWindowsMemory {
+    pub(crate) fn new(ctx: Context, container_id: Id) -> Self {
+        Self {
+            ctx,
+            container_id,
+        }
+    }
+
+    /// The ids of the windows in the container, in rendering order, i.e. the LAST one is on TOP.
+    pub fn window_ids(&self) -> Vec<Id> {
+        self.load_container()
+            .state
+            .rendering_stack
+    }
+
+    /// The windows added to the container the last time its windows were shown, in the order they
+    /// were added, including the closed ones.
+    pub fn known_windows(&self) -> Vec<KnownToolWindow> {
+        self.load_container()
+            .state
+            .known_windows
+    }
+
+    /// `None` if the window wasn't added to the container the last time its windows were shown.
+    pub fn is_open(&self, id: Id) -> Option<bool> {
+        self.load_container()
+            .state
+            .known_windows
+            .into_iter()
+            .find(|window| window.id == id)
+            .map(|window| window.open)
+    }
+
+    /// Close the window, leaving it out as if it hadn't been added to the builder, or open it again.
+    /// An opened window is brought to the front. Ignored if the window wasn't added to the
+    /// container the last time its windows were shown.
+    pub fn set_open(&self, id: Id, open: bool) {
+        self.modify_container(|persistence| persistence.state.set_open(id, open));
+    }
+
+    pub fn position(&self, id: Id) -> Option<Pos2> {
+        ToolWindow::load(&self.ctx, id).map(|window| window.state.position)
+    }
+
+    pub fn size(&self, id: Id) -> Option<Vec2> {
+        ToolWindow::load(&self.ctx, id).map(|window| window.state.size)
+    }
+
+    pub fn is_collapsed(&self, id: Id) -> Option<bool> {
+        ToolWindow::load(&self.ctx, id).map(|window| window.state.collapsed)
+    }
+
+    pub fn is_minimized(&self, id: Id) -> Option<bool> {
+        ToolWindow::load(&self.ctx, id).map(|window| window.state.minimized)
+    }
+
+    pub fn is_maximized(&self, id: Id) -> Option<bool> {
+        ToolWindow::load(&self.ctx, id).map(|window| window.state.maximized)
+    }
+
+    /// Move the window, relative to the container's top-left corner. Cancels any drag in progress.
+    pub fn set_position(&self, id: Id, position: impl Into<Pos2>) {
+        let position = position.into();
+        self.modify_window(id, |window| {
+            window.state.position = position;
+            window.state.drag_state = None;
+            window.state.resize_drag_state = None;
+        });
+    }
+
+    /// Resize the window. Cancels any drag in progress.
+    pub fn set_size(&self, id: Id, size: impl Into<Vec2>) {
+        let size = size.into();
+        self.modify_window(id, |window| {
+            window.state.size = size;
+            window.state.drag_state = None;
+            window.state.resize_drag_state = None;
+        });
+    }
+
+    pub fn set_collapsed(&self, id: Id, collapsed: bool) {
+        self.modify_window(id, |window| window.state.collapsed = collapsed);
+    }
+
+    /// Move the window to the container's shelf, or restore it from there. Cancels any drag in
+    /// progress.
+    pub fn set_minimized(&self, id: Id, minimized: bool) {
+        self.modify_window(id, |window| {
+            window.state.minimized = minimized;
+            window.state.drag_state = None;
+            window.state.resize_drag_state = None;
+        });
+    }
+
+    /// Make the window fill the container, or restore it to its previous position and size. Cancels
+    /// any drag in progress.
+    pub fn set_maximized(&self, id: Id, maximized: bool) {
+        self.modify_window(id, |window| {
+            window.state.maximized = maximized;
+            window.state.drag_state = None;
+            window.state.resize_drag_state = None;
+        });
+    }
+
+    /// Fit the window to its content the next time it's shown, within its `min_size` and
+    /// `max_size`. Ignored if the window is collapsed, docked or maximized then. Reported as a
+    /// `ToolWindowAction::Resized` if the size changes.
+    pub fn auto_size_once(&self, id: Id) {
+        self.modify_window(id, |window| window.state.auto_size_requested = true);
+    }
+
+    /// Move the window with the arrow keys, and resize it with Shift and the arrow keys, until
+    /// Enter or Escape is pressed. Holding Ctrl moves and resizes it in bigger steps. The window is
+    /// brought to the front, and this ends early if another window is brought to the front, or if
+    /// the window is dragged, docked or maximized.
+    ///
+    /// Enter reports the change as `ToolWindowAction::Moved` and/or `ToolWindowAction::Resized`,
+    /// and Escape puts the window back where it was. It can also be started from the title bar's
+    /// context menu, or with [`crate::KeyboardNavigation::move_resize`].
+    pub fn move_resize_with_keyboard(&self, id: Id) {
+        self.modify_window(id, |window| window.start_keyboard_move_resize());
+        self.bring_to_front(id);
+    }
+
+    pub fn bring_to_front(&self, id: Id) {
+        self.modify_container(|persistence| persistence.state.bring_to_front(id));
+    }
+
+    pub fn send_to_back(&self, id: Id) {
+        self.modify_container(|persistence| persistence.state.send_to_back(id));
+    }
+
+    /// Forget the window's position, size, collapsed, minimized and maximized state, so it's shown
+    /// using its `default_pos`/`default_size` again.
+    pub fn reset_to_default(&self, id: Id) {
+        trace!("resetting window to default. id: {:?}", id);
+        self.ctx
+            .data_mut(|d| d.remove::<ToolWindowState>(id));
+        self.ctx.request_repaint();
+    }
+
+    /// [`Self::reset_to_default`] for every window in the container. The stacking order is kept.
+    pub fn reset_all_to_default(&self) {
+        for id in self.window_ids() {
+            self.reset_to_default(id);
+        }
+    }
+
+    /// A checkbox for each of the container's known windows, to open and close it, followed by a
+    /// "Reset all" button, which opens every window and resets it to its defaults. Windows that
+    /// aren't `closable` can't be closed from here. For use in a menu, e.g.
+    /// `ui.menu_button("Windows", |ui| memory.windows_menu_ui(ui))`.
+    pub fn windows_menu_ui(&self, ui: &mut Ui) {
+        let known_windows = self.known_windows();
+        for window in known_windows.iter() {
+            let mut open = window.open;
+            let mut atoms = Atoms::new(window.title.clone());
+            if let Some(icon) = &window.icon {
+                atoms.push_left(icon.atom(ui));
+            }
+            if ui
+                .add_enabled(window.closable || !open, Checkbox::new(&mut open, atoms))
+                .changed()
+            {
+                self.set_open(window.id, open);
+            }
+        }
+
+        ui.separator();
+        if ui.button("Reset all").clicked() {
+            for window in known_windows.iter() {
+                self.set_open(window.id, true);
+                self.reset_to_default(window.id);
+            }
+        }
+    }
+
+    /// Take a snapshot of the position, size, collapsed, minimized and maximized state and stacking
+    /// order of every window in the container that's been shown at least once.
+    pub fn layout(&self, name: impl Into<String>) -> ToolWindowsLayout {
+        let windows = self
+            .window_ids()
+            .into_iter()
+            .filter_map(|id| ToolWindow::load(&self.ctx, id))
+            .map(|window| ToolWindowLayout {
+                id: window.id,
+                position: window.state.position,
+                size: window.state.size,
+                collapsed: window.state.collapsed,
+                minimized: window.state.minimized,
+                maximized: window.state.maximized,
+            })
+            .collect();
+
+        ToolWindowsLayout {
+            name: name.into(),
+            windows,
+        }
+    }
+
+    /// Restore a snapshot taken with [`Self::layout`].
+    ///
+    /// Windows in the layout that haven't been shown yet will use the layout instead of their
+    /// defaults once they are, and are stacked on top like any other newly shown window. Windows
+    /// that aren't in the layout keep their current position, size, collapsed, minimized and
+    /// maximized state, and are stacked below the windows that are.
+    pub fn apply_layout(&self, layout: &ToolWindowsLayout) {
+        for window_layout in &layout.windows {
+            let mut window = ToolWindow::load(&self.ctx, window_layout.id).unwrap_or_else(|| ToolWindow {
+                id: window_layout.id,
+                state: ToolWindowState::default(),
+            });
+            window.state.position = window_layout.position;
+            window.state.size = window_layout.size;
+            window.state.collapsed = window_layout.collapsed;
+            window.state.minimized = window_layout.minimized;
+            window.state.maximized = window_layout.maximized;
+            window.state.drag_state = None;
+            window.state.resize_drag_state = None;
+            window.store(&self.ctx);
+        }
+
+        self.modify_container(|persistence| {
+            for window_layout in &layout.windows {
+                // Windows that haven't been shown yet are stacked when they are.
+                if persistence
+                    .state
+                    .rendering_stack
+                    .contains(&window_layout.id)
+                {
+                    persistence
+                        .state
+                        .bring_to_front(window_layout.id);
+                }
+            }
+        });
+    }
+
+    fn load_container(&self) -> ToolWindowsStatePersistence {
+        ToolWindowsStatePersistence::load_or_default(&self.ctx, ToolWindows::state_id(self.container_id))
+    }
+
+    fn modify_container(&self, f: impl FnOnce(&mut ToolWindowsStatePersistence)) {
+        let mut persistence = self.load_container();
+        f(&mut persistence);
+        persistence.store(&self.ctx);
+        self.ctx.request_repaint();
+    }
+
+    fn modify_window(&self, id: Id, f: impl FnOnce(&mut ToolWindow)) {
+        let Some(mut window) = ToolWindow::load(&self.ctx, id) else {
+            trace!("ignoring change to a window that hasn't been shown yet. id: {:?}", id);
+            return;
+        };
+        f(&mut window);
+        window.store(&self.ctx);
+        self.ctx.request_repaint();
+    }
+}
@@ -1,31 +1,144 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::ops::Range;
 
+pub use docking::DockSide;
+use docking::{DOCK_EDGE_THRESHOLD, DockSlot, DockedWindow};
 use egui::collapsing_header::CollapsingState;
 use egui::emath::easing;
 use egui::{
-    Align, Color32, Context, CornerRadius, CursorIcon, Frame, Id, Layout, Pos2, Rect, Sense, Style, Ui, UiBuilder,
-    Vec2, Vec2b, vec2,
+    Align, Align2, AsIdSalt, AtomExt, Atoms, Color32, Context, CornerRadius, CursorIcon, Frame, Id, IdSalt, Layout,
+    Pos2, Rect, Response, ScrollArea, Sense, StrokeKind, Ui, UiBuilder, Vec2, Vec2b, WidgetInfo, WidgetText,
+    WidgetType, vec2,
 };
-use log::trace;
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub use icon::ToolWindowIcon;
+pub use keyboard::KeyboardNavigation;
+use keyboard::{KeyboardState, MoveResizeInput, SwitchableWindow};
+pub use layout::{ToolWindowLayout, ToolWindowsLayout};
+use log::{trace, warn};
+pub use memory::ToolWindowsMemory;
+pub use registry::KnownToolWindow;
+pub use response::{ToolWindowResponse, ToolWindowsResponse};
+use shelf::MinimizedWindow;
+pub use snapping::Snapping;
+use snapping::{SnapGuides, SnapTargets};
+pub use style::ToolWindowsStyle;
+use tabs::{Tab, ToolWindowGroup};
+
+mod docking;
+mod icon;
+mod keyboard;
+mod layout;
+mod memory;
+#[cfg(feature = "persistence")]
+mod persistence;
+mod registry;
+mod response;
+mod shelf;
+mod snapping;
+mod style;
+mod tabs;
+
+/// Positions are relative to the container's top-left corner, like `default_pos`.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ToolWindowAction {
     CloseRequested,
+    /// The window was moved, either by dragging its title bar or by resizing it from its left or
+    /// top edge. Reported once, when the drag stops.
+    Moved {
+        from: Pos2,
+        to: Pos2,
+    },
+    /// The window was resized. Reported once, when the drag stops.
+    Resized {
+        from: Vec2,
+        to: Vec2,
+    },
+    Collapsed,
+    Expanded,
+    /// The window was raised to the top of the container's rendering stack by the user.
+    BroughtToFront,
+    /// A move (title bar) or resize (edge or corner) drag started.
+    DragStarted,
+    /// A move or resize drag stopped, reported before the corresponding `Moved` and `Resized`.
+    DragStopped,
+    /// The window is shown for the first time since it was added to the container, either for the
+    /// very first time or after being left out of the builder for one or more frames.
+    FirstShown,
+    /// The window was added to a tab group, either by being dropped onto another window's title bar
+    /// or by another window being dropped onto its title bar.
+    Grouped,
+    /// The window was removed from its tab group, either by dragging its tab out of the title bar
+    /// or because it was the last tab left in the group.
+    Ungrouped,
+    /// The window's tab was selected, so it's now shown in place of the group's other tabs.
+    TabSelected,
+    /// The window was dropped against a container edge and docked to it, see `ToolWindows::docking`.
+    Docked {
+        side: DockSide,
+    },
+    /// The window was undocked by dragging its title bar.
+    Undocked,
+    /// The window's minimize button was clicked, it's shown on the container's shelf from the next
+    /// frame, see `ToolWindowInstanceBuilder::minimizable`.
+    Minimized,
+    /// The window's shelf button was clicked, it's shown again from the next frame.
+    Restored,
+    /// The window's maximize button was clicked, or its title bar was double-clicked, see
+    /// `ToolWindowInstanceBuilder::maximizable`.
+    Maximized,
+    /// The window was restored to its position and size from before it was maximized.
+    Unmaximized,
+}
+
+/// What double-clicking a window's title bar does, see
+/// `ToolWindowInstanceBuilder::title_bar_double_click`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TitleBarDoubleClick {
+    /// Maximize the window, or restore it if it's maximized. Only if it's `maximizable`.
+    #[default]
+    ToggleMaximized,
+    /// Fit the window to its content, see `ToolWindowsMemory::auto_size_once`.
+    AutoSize,
+    Nothing,
 }
 
 /// What a single window contributed this frame, for `ToolWindows::windows` to aggregate across
 /// all windows once every window has been processed (see `ToolWindowsState::sticky_content_extent`
 /// for why this can't just be reported per-window as it's produced).
 struct ToolWindowFrameResult {
-    actions: Vec<ToolWindowAction>,
+    /// `hovered` and `focused` are left `false` here; they can only be resolved once every window
+    /// has been shown, since a window shown later may still cover this one or be brought to front.
+    response: ToolWindowResponse,
     /// This window's extent for the current frame, in content space (i.e. relative to
     /// `content_origin`, not translated into absolute/screen coordinates) so it stays valid even
     /// if `content_origin` itself moves (e.g. due to scrolling) before it's used.
     content_space_rect: Rect,
     /// Whether this window is currently being dragged or resized.
     dragging: bool,
+    /// The window whose title bar this window was dropped onto, see `ToolWindowsState::group_windows`.
+    dropped_on: Option<Id>,
+    /// The response of each tab in the title bar, in tab order, when this window is the active tab
+    /// of a group.
+    tab_responses: Vec<(Id, Response)>,
+    /// Actions for the group's other tabs, which aren't shown themselves.
+    tab_actions: Vec<(Id, ToolWindowAction)>,
+    /// The container edge this window was dropped against, see `ToolWindows::docking`.
+    docked_to: Option<DockSide>,
+}
+
+/// The container's settings that apply to all of its windows.
+#[derive(Clone, Copy)]
+struct ContainerParameters<'c> {
+    scrollable: bool,
+    /// The screen position of the container's content space, see `ToolWindows::windows`.
+    content_origin: Pos2,
+    snapping: Option<&'c Snapping>,
+    docking: bool,
+    /// The container's visible area, less the strips taken by any docked windows, in screen coordinates.
+    free_rect: Rect,
+    resize_grab_thickness: f32,
+    style: &'c ToolWindowsStyle,
 }
 
 struct ToolWindow {
@@ -37,33 +150,87 @@ impl ToolWindow {
     fn show(
         &mut self,
         ui: &mut Ui,
-        params: ToolWindowParameters,
+        params: ToolWindowParameters<'_>,
         state: &mut ToolWindowsState,
-        scrollable: bool,
-        content_origin: Pos2,
+        container: &ContainerParameters<'_>,
+        tabs: &[Tab],
+        dock: Option<DockSlot>,
     ) -> ToolWindowFrameResult {
+        let ContainerParameters {
+            scrollable,
+            content_origin,
+            snapping,
+            docking,
+            free_rect,
+            resize_grab_thickness: edge_thickness,
+            style: container_style,
+        } = *container;
+
         let mut actions = vec![];
+        let mut tab_actions = vec![];
+        let mut tab_responses = vec![];
+        let mut dropped_on = None;
+        let mut docked_to = None;
 
         let is_topmost = state.is_topmost(self.id);
 
+        // These are owned by the builder, not by the persisted state, so they're re-applied every
+        // frame; a window that's been made fixed since it was last stored must not keep whatever
+        // size or drag it had back then.
+        // A fixed size or auto sized window can't be resized, whichever order the builder's options
+        // were set in.
+        self.state.resizable = if params.fixed_size.is_some() || params.auto_sized {
+            Vec2b::FALSE
+        } else {
+            params.resizable
+        };
+        if let Some(fixed_size) = params.fixed_size {
+            self.state.size = fixed_size;
+        }
+        if !params.movable {
+            self.state.drag_state = None;
+        }
+
         let ctx = ui.ctx().clone();
         let id = ui.make_persistent_id(
             self.id
                 .with("__tool_window_persistent_id"),
         );
         let mut collapsing_state = CollapsingState::load_with_default_open(&ctx, id, true);
+        // `self.state.collapsed` is the source of truth, e.g. it may have been changed via
+        // `ToolWindowsMemory::set_collapsed` since the last frame.
+        if collapsing_state.is_open() == self.state.collapsed {
+            collapsing_state.set_open(!self.state.collapsed);
+        }
 
         let visuals = ui.visuals().clone();
 
-        let title_bar_height = 24.0;
-        let inner_margin = 2;
-        let outer_margin = 0;
-        let edge_thickness = 4.0;
-        let position_margin = 16.0;
+        // The window's own style, if it has one, replaces the container's entirely.
+        let window_style = params
+            .style
+            .as_ref()
+            .unwrap_or(container_style);
+        let title_bar_height = window_style.title_bar_height;
+        let inner_margin = window_style.inner_margin;
+        let outer_margin = window_style.outer_margin;
+        let position_margin = window_style.position_margin;
 
         let resize_corner_size = ui.visuals().resize_corner_size;
         let baseline_min_size = vec2(100.0, title_bar_height);
 
+        // The constraints may have changed since the size was stored, or may not suit the default
+        // size. The content's measured minimum only applies while the window is being resized.
+        if params.fixed_size.is_none() && self.state.resize_drag_state.is_none() {
+            self.state.size = Self::constrain_size(
+                self.state.size,
+                params.min_size,
+                params.max_size,
+                params.keep_aspect_ratio,
+                title_bar_height,
+                true,
+            );
+        }
+
         // The content is only rendered (and therefore only measurable) when expanded and when a
         // content closure was actually supplied.
         let can_measure_content = !self.state.collapsed && params.content_fn.is_some();
@@ -86,41 +253,155 @@ fn show(
         // started this frame is anchored to (see below), so grabbing a window that's currently
         // being displayed clamped moves it from where it visibly is, not from its unclamped
         // `self.state.position`.
-        let display_position = if scrollable {
+        //
+        // Windows docked to the container's edges take their strips out of the viewport first, see
+        // `ToolWindows::docking`. A docked window is displayed in its strip instead, and a maximized
+        // window fills whatever's left of the viewport. Their `self.state.position` and
+        // `self.state.size` are likewise left alone, for when they're undocked or restored again.
+
+        // The screen position that `position` (and `display_position`) is relative to.
+        let position_origin = if scrollable { content_origin } else { ui_clip_rect.min };
+
+        let border_adjust = Vec2::splat(window_style.margins());
+
+        // The outer window size for a given (uncollapsed) content size.
+        let outer_size = |size: Vec2| {
+            let size = if self.state.collapsed {
+                vec2(size.x, title_bar_height)
+            } else {
+                size
+            };
+            size + border_adjust
+        };
+
+        // Docking takes precedence over maximizing, a docked window can't be maximized.
+        let maximized = self.state.maximized && dock.is_none();
+
+        // A collapsed window that's docked to the left or right, or maximized, only takes up the
+        // top of its area.
+        let anchored_rect = dock
+            .map(|slot| slot.strip(outer_size(self.state.size)))
+            .or(maximized.then_some(free_rect))
+            .map(|area| {
+                let height = if self.state.collapsed {
+                    area.height()
+                        .min(outer_size(self.state.size).y)
+                } else {
+                    area.height()
+                };
+                Rect::from_min_size(area.min, vec2(area.width(), height))
+            });
+
+        // Fit the window to its content, either every frame or once on request. Not while the window
+        // is docked, maximized or being resized, since its size isn't up to the content then.
+        let auto_size_requested = std::mem::take(&mut self.state.auto_size_requested);
+        let auto_sizing = (params.auto_sized || auto_size_requested)
+            && can_measure_content
+            && anchored_rect.is_none()
+            && params.fixed_size.is_none()
+            && self.state.resize_drag_state.is_none();
+
+        // The arrow keys move the window and, with Shift held, resize it, clamped the same way as
+        // when it's dragged, until Enter or Escape is pressed. It ends as soon as the window can't be
+        // moved or resized like this any more, keeping what's been done so far.
+        if let Some(move_resize) = self.state.keyboard_move_resize {
+            let input = if anchored_rect.is_some()
+                || !is_topmost
+                || self.state.drag_state.is_some()
+                || self.state.resize_drag_state.is_some()
+            {
+                MoveResizeInput {
+                    commit: true,
+                    ..Default::default()
+                }
+            } else {
+                keyboard::move_resize_input(&ctx)
+            };
+
+            if params.movable && input.movement != Vec2::ZERO {
+                let mut position = self.state.position + input.movement;
+                if scrollable {
+                    position = position.max(Pos2::ZERO);
+                } else {
+                    Self::clamp_offset(
+                        free_rect.translate(-position_origin.to_vec2()),
+                        position_margin,
+                        &mut position,
+                    );
+                }
+                self.state.position = position;
+            }
+
+            // A collapsed window's height can't be changed, like when it's resized by dragging.
+            let resizable = Vec2b::new(self.state.resizable.x, self.state.resizable.y && !self.state.collapsed);
+            let resize = vec2(
+                if resizable.x { input.resize.x } else { 0.0 },
+                if resizable.y { input.resize.y } else { 0.0 },
+            );
+            if params.fixed_size.is_none() && resize != Vec2::ZERO {
+                self.state.size = Self::constrain_size(
+                    self.state.size + resize,
+                    baseline_min_size
+                        .max(self.state.content_min_size)
+                        .max(params.min_size),
+                    params.max_size,
+                    params.keep_aspect_ratio,
+                    title_bar_height,
+                    resize.x != 0.0,
+                );
+            }
+
+            if input.cancel {
+                trace!("cancelling keyboard move/resize. id: {:?}", self.id);
+                self.state.position = move_resize.initial_position;
+                self.state.size = move_resize.initial_size;
+                self.state.keyboard_move_resize = None;
+            } else if input.commit {
+                trace!("finished keyboard move/resize. id: {:?}", self.id);
+                if move_resize.initial_size != self.state.size {
+                    actions.push(ToolWindowAction::Resized {
+                        from: move_resize.initial_size,
+                        to: self.state.size,
+                    });
+                }
+                if move_resize.initial_position != self.state.position {
+                    actions.push(ToolWindowAction::Moved {
+                        from: move_resize.initial_position,
+                        to: self.state.position,
+                    });
+                }
+                self.state.keyboard_move_resize = None;
+            }
+        }
+
+        let display_position = if let Some(anchored_rect) = anchored_rect {
+            anchored_rect.min - position_origin.to_vec2()
+        } else if scrollable {
             self.state.position.x = self.state.position.x.max(0.0);
             self.state.position.y = self.state.position.y.max(0.0);
-            self.state.position
-        } else {
-            let available = Vec2::new(
-                (ui_clip_rect.width() - position_margin).max(position_margin),
-                (ui_clip_rect.height() - position_margin).max(position_margin),
-            );
 
+            // Only the strips docked to the left and top are kept clear of, at the start of the
+            // content, since the window scrolls along with the content rather than the viewport.
+            let docked_inset = free_rect.min - ui_clip_rect.min;
+            self.state
+                .position
+                .max(docked_inset.to_pos2())
+        } else {
             let mut display_position = self.state.position;
-            Self::clamp_offset(available, &mut display_position);
+            Self::clamp_offset(
+                free_rect.translate(-position_origin.to_vec2()),
+                position_margin,
+                &mut display_position,
+            );
             display_position
         };
 
-        let top_left = if scrollable {
-            content_origin + display_position.to_vec2()
-        } else {
-            ui_clip_rect.min + display_position.to_vec2()
-        };
-
-        let border_adjust_splat = (inner_margin + outer_margin) * 2;
-        let border_adjust = Vec2::splat(border_adjust_splat as f32);
+        let top_left = position_origin + display_position.to_vec2();
 
         // Builds the outer window rect for a given (uncollapsed) content size.
-        let rect_for_size = |size: Vec2| {
-            let actual_size = if self.state.collapsed {
-                vec2(size.x, title_bar_height)
-            } else {
-                size
-            } + border_adjust;
-            Rect::from_min_size(top_left, actual_size)
-        };
+        let rect_for_size = |size: Vec2| Rect::from_min_size(top_left, outer_size(size));
 
-        let rect = rect_for_size(self.state.size);
+        let rect = anchored_rect.unwrap_or_else(|| rect_for_size(self.state.size));
         debug_rect(ui, rect, Color32::BLUE);
 
         // This window's full extent - including any part currently clipped by the container's
@@ -130,7 +411,7 @@ fn show(
         // doesn't fully fit is silently clipped with no way to scroll to the rest of it.
         let content_space_rect = rect.translate(-content_origin.to_vec2());
 
-        let corner_radius = CornerRadius::same(6);
+        let corner_radius = window_style.corner_radius(&visuals);
 
         //
         // input shield
@@ -150,7 +431,14 @@ fn show(
         // window. Without a *drag*-sensing shield, drag-only widgets underneath (table column
         // dividers, drag-values, ...) would still be picked as the drag hit and respond through the
         // window on top of them.
-        let shield_response = ui.interact(rect, self.id.with("__tool_window_shield"), Sense::click_and_drag());
+        //
+        // Like the title bar and the resize handles, the shield is only for the pointer, so it's
+        // left out of the widgets Tab moves keyboard focus through.
+        let shield_response = ui.interact(
+            rect,
+            self.id.with("__tool_window_shield"),
+            Sense::click_and_drag() - Sense::FOCUSABLE,
+        );
         if shield_response.clicked() || shield_response.drag_started() {
             trace!(
                 "shield interaction, bringing to front. id: {:?}, rendering_stack: {:?}",
@@ -159,11 +447,23 @@ fn show(
             state.bring_to_front(self.id);
         }
 
+        // The shield also stands in for the window in the accessibility tree; the title bar and the
+        // content are parented to it below.
+        let title_text = params.title.text().to_string();
+        shield_response.widget_info(|| WidgetInfo::labeled(WidgetType::Window, true, &title_text));
+        let accessibility_id = shield_response.id;
+
         let mut left_dragging = false;
         let mut right_dragging = false;
         let mut top_dragging = false;
         let mut bottom_dragging = false;
 
+        // The sides of the window that have a resize handle along them, which the title bar leaves
+        // room for, see `title_bar_sense_rect`.
+        let mut left_handle = false;
+        let mut right_handle = false;
+        let mut top_handle = false;
+
         // Whichever edge/corner just started being dragged this frame, captured so we can seed
         // `resize_drag_state` below with a pivot taken at the moment the drag began.
         let mut drag_started: Option<(bool, bool, bool, bool, Pos2)> = None;
@@ -190,10 +490,29 @@ fn show(
                 }),
             ];
 
+            // Only register the handles for axes that can actually be resized, so a fixed axis
+            // doesn't show a resize cursor or steal drags from whatever is underneath. A docked
+            // window can only be resized from the edge facing the rest of the container.
+            let resizable = self.state.resizable;
+            let edges = edges
+                .into_iter()
+                .filter(|(edge, _)| match *edge {
+                    "left" | "right" => resizable.x,
+                    _ => resizable.y,
+                })
+                .filter(|(edge, _)| dock.is_none_or(|slot| slot.side.resize_edge() == *edge))
+                .filter(|_| !maximized);
+
             for (edge, edge_rect) in edges {
                 debug_rect(ui, edge_rect, Color32::ORANGE);
 
-                let resp = ui.interact(edge_rect, id.with(edge), Sense::drag());
+                let resp = ui.interact(edge_rect, id.with(edge), Sense::drag() - Sense::FOCUSABLE);
+                match edge {
+                    "left" => left_handle = true,
+                    "right" => right_handle = true,
+                    "top" => top_handle = true,
+                    _ => {}
+                }
 
                 if resp.hovered() {
                     match edge {
@@ -213,52 +532,119 @@ fn show(
                     }
                 }
 
-                if resp.drag_started() {
-                    if let Some(pointer) = resp.interact_pointer_pos() {
-                        let (left, right, top, bottom) = match edge {
-                            "left" => (true, false, false, false),
-                            "right" => (false, true, false, false),
-                            "top" => (false, false, true, false),
-                            "bottom" => (false, false, false, true),
-                            _ => (false, false, false, false),
-                        };
-                        drag_started = Some((left, right, top, bottom, pointer));
-                    }
+                if resp.drag_started()
+                    && let Some(pointer) = resp.interact_pointer_pos()
+                {
+                    let (left, right, top, bottom) = match edge {
+                        "left" => (true, false, false, false),
+                        "right" => (false, true, false, false),
+                        "top" => (false, false, true, false),
+                        "bottom" => (false, false, false, true),
+                        _ => (false, false, false, false),
+                    };
+                    drag_started = Some((left, right, top, bottom, pointer));
                 }
             }
 
-            let corner_id = self
-                .state
-                .resizable
-                .any()
-                .then(|| id.with("__resize_corner"));
+            let corners_resizable = self.state.resizable.any() && dock.is_none() && !maximized;
+
+            // The other corners don't have a visible grip like the bottom-right one, just a handle
+            // where the edges meet, registered after the edges so it takes precedence over them.
+            // The title bar is registered after all the handles, so it's the title bar that leaves
+            // room for the top corners, see `title_bar_sense_rect`.
+            let corners = [
+                (
+                    "left_top",
+                    rect.left_top(),
+                    CursorIcon::ResizeNwSe,
+                    (true, false, true, false),
+                ),
+                (
+                    "right_top",
+                    rect.right_top(),
+                    CursorIcon::ResizeNeSw,
+                    (false, true, true, false),
+                ),
+                (
+                    "left_bottom",
+                    rect.left_bottom(),
+                    CursorIcon::ResizeNeSw,
+                    (true, false, false, true),
+                ),
+            ];
+
+            for (corner, corner_pos, cursor, (left, right, top, bottom)) in corners
+                .into_iter()
+                .filter(|_| corners_resizable)
+            {
+                left_handle |= left;
+                right_handle |= right;
+                top_handle |= top;
+
+                // A corner is shared by both axes, so it only resizes the axes that are enabled.
+                let (left, right) = (left && resizable.x, right && resizable.x);
+                let (top, bottom) = (top && resizable.y, bottom && resizable.y);
+
+                let corner_rect = Rect::from_center_size(corner_pos, Vec2::splat(edge_thickness * 2.0));
+                debug_rect(ui, corner_rect, Color32::ORANGE);
+
+                let resp = ui.interact(corner_rect, id.with(corner), Sense::drag() - Sense::FOCUSABLE);
+
+                if resp.hovered() || resp.dragged() {
+                    ctx.set_cursor_icon(corner_cursor(cursor, resizable));
+                }
+
+                if resp.dragged() {
+                    left_dragging |= left;
+                    right_dragging |= right;
+                    top_dragging |= top;
+                    bottom_dragging |= bottom;
+                }
+
+                if resp.drag_started()
+                    && let Some(pointer) = resp.interact_pointer_pos()
+                {
+                    drag_started = Some((left, right, top, bottom, pointer));
+                }
+            }
+
+            let corner_id = corners_resizable.then(|| id.with("__resize_corner"));
 
             let corner_response = if let Some(corner_id) = corner_id {
                 let corner_size = Vec2::splat(resize_corner_size);
-                let corner_rect =
+                let grip_rect =
                     egui::Rect::from_min_size(rect.right_bottom() - corner_size - border_adjust, corner_size);
+                // Can be grabbed anywhere the edges can, as well as by the grip.
+                let corner_rect = grip_rect.union(Rect::from_center_size(
+                    rect.right_bottom(),
+                    Vec2::splat(edge_thickness * 2.0),
+                ));
                 debug_rect(ui, corner_rect, Color32::ORANGE);
 
-                Some(ui.interact(corner_rect, corner_id, Sense::drag()))
+                Some((
+                    ui.interact(corner_rect, corner_id, Sense::drag() - Sense::FOCUSABLE),
+                    grip_rect,
+                ))
             } else {
                 None
             };
 
-            if let Some(corner_response) = &corner_response {
+            if let Some((corner_response, _)) = &corner_response {
                 if corner_response.hovered() || corner_response.dragged() {
                     ui.ctx()
-                        .set_cursor_icon(CursorIcon::ResizeNwSe);
+                        .set_cursor_icon(corner_cursor(CursorIcon::ResizeNwSe, resizable));
                 }
 
+                // The corner is shared by both axes, so it only resizes the axes that are enabled.
                 if corner_response.dragged() {
-                    right_dragging = true;
-                    bottom_dragging = true;
+                    right_dragging |= resizable.x;
+                    bottom_dragging |= resizable.y;
                 }
 
-                if corner_response.drag_started() {
-                    if let Some(pointer) = corner_response.interact_pointer_pos() {
-                        drag_started = Some((false, true, false, true, pointer));
-                    }
+                if corner_response.drag_started()
+                    && let Some(pointer) = corner_response.interact_pointer_pos()
+                {
+                    drag_started = Some((false, resizable.x, false, resizable.y, pointer));
                 }
             }
 
@@ -285,8 +671,12 @@ fn show(
                 bottom,
                 drag_pivot,
                 initial_size: self.state.size,
-                initial_position: display_position,
+                initial_position: match dock {
+                    Some(_) => self.state.position,
+                    None => display_position,
+                },
             });
+            actions.push(ToolWindowAction::DragStarted);
         }
 
         // `content_min_size` is deliberately left alone here: it's the best-known minimum and
@@ -295,7 +685,21 @@ fn show(
         // so the *next* drag starts from a fresh pivot and re-measures once.
         if !is_actively_resizing {
             self.state.measured_for_current_drag = false;
-            self.state.resize_drag_state = None;
+            if let Some(drag) = self.state.resize_drag_state.take() {
+                actions.push(ToolWindowAction::DragStopped);
+                if drag.initial_size != self.state.size {
+                    actions.push(ToolWindowAction::Resized {
+                        from: drag.initial_size,
+                        to: self.state.size,
+                    });
+                }
+                if drag.initial_position != self.state.position {
+                    actions.push(ToolWindowAction::Moved {
+                        from: drag.initial_position,
+                        to: self.state.position,
+                    });
+                }
+            }
         }
 
         // The first frame of a drag doesn't yet know the content's true minimum size, so it runs
@@ -317,31 +721,96 @@ fn show(
         // computation always starts fresh from the fixed pivot, once a min-size clamp holds the
         // size steady, the window resumes growing exactly when the pointer's displacement from
         // the pivot crosses back past the point where the clamp took effect.
-        if !needs_sizing_pass {
-            if let Some(drag) = self.state.resize_drag_state {
-                if let Some(pointer) = ctx.input(|i| i.pointer.interact_pos()) {
-                    let delta = pointer - drag.drag_pivot;
-
-                    let mut size = drag.initial_size;
-                    let mut position = drag.initial_position;
-
-                    if drag.right {
-                        size.x = (drag.initial_size.x + delta.x).max(min_size.x);
-                    } else if drag.left {
-                        size.x = (drag.initial_size.x - delta.x).max(min_size.x);
-                        position.x = drag.initial_position.x + drag.initial_size.x - size.x;
-                    }
+        // Only worked out while the window is actually being moved or resized, and not at all while
+        // the bypass modifiers are held.
+        let snap_targets = snapping
+            .filter(|_| dock.is_none())
+            .filter(|_| self.state.drag_state.is_some() || self.state.resize_drag_state.is_some())
+            .filter(|snapping| {
+                let bypass = snapping.bypass_modifiers;
+                bypass.is_none() || !ctx.input(|i| i.modifiers.contains(bypass))
+            })
+            .map(|snapping| {
+                let viewport = free_rect.translate(-position_origin.to_vec2());
+                let other_windows = state
+                    .window_rects
+                    .iter()
+                    .filter(|(id, _)| **id != self.id)
+                    .map(|(_, rect)| *rect);
+                SnapTargets::new(snapping, viewport, other_windows)
+            });
+        let mut snap_guides = SnapGuides::default();
 
-                    if drag.bottom {
-                        size.y = (drag.initial_size.y + delta.y).max(min_size.y);
-                    } else if drag.top {
-                        size.y = (drag.initial_size.y - delta.y).max(min_size.y);
-                        position.y = drag.initial_position.y + drag.initial_size.y - size.y;
-                    }
+        if !needs_sizing_pass
+            && let Some(drag) = self.state.resize_drag_state
+            && let Some(pointer) = ctx.input(|i| i.pointer.interact_pos())
+        {
+            let mut delta = pointer - drag.drag_pivot;
+
+            // Snap whichever edges are being dragged by nudging the pointer's displacement, before
+            // the min-size clamps below, so snapping can never shrink the window past its minimum.
+            if let Some(snap_targets) = &snap_targets {
+                let initial_max = drag.initial_position + drag.initial_size + border_adjust;
+                if drag.right {
+                    delta.x += snap_targets.snap_x(initial_max.x + delta.x, &mut snap_guides);
+                } else if drag.left {
+                    delta.x += snap_targets.snap_x(drag.initial_position.x + delta.x, &mut snap_guides);
+                }
+                if drag.bottom {
+                    delta.y += snap_targets.snap_y(initial_max.y + delta.y, &mut snap_guides);
+                } else if drag.top {
+                    delta.y += snap_targets.snap_y(drag.initial_position.y + delta.y, &mut snap_guides);
+                }
+            }
+
+            let mut size = drag.initial_size;
+            let mut position = drag.initial_position;
+
+            if drag.right {
+                size.x = drag.initial_size.x + delta.x;
+            } else if drag.left {
+                size.x = drag.initial_size.x - delta.x;
+            }
+
+            if drag.bottom {
+                size.y = drag.initial_size.y + delta.y;
+            } else if drag.top {
+                size.y = drag.initial_size.y - delta.y;
+            }
 
-                    self.state.size = size;
-                    self.state.position = position;
+            // When the aspect ratio is kept, the axis being dragged decides the size, or for a
+            // corner, whichever axis the pointer has moved furthest along relative to the size.
+            let x_decides = match (drag.left || drag.right, drag.top || drag.bottom) {
+                (true, false) => true,
+                (false, true) => false,
+                _ => {
+                    let content_height = |size: Vec2| size.y - title_bar_height;
+                    (size.x / drag.initial_size.x - 1.0).abs()
+                        >= (content_height(size) / content_height(drag.initial_size) - 1.0).abs()
                 }
+            };
+            let size = Self::constrain_size(
+                size,
+                min_size.max(params.min_size),
+                params.max_size,
+                params.keep_aspect_ratio,
+                title_bar_height,
+                x_decides,
+            );
+
+            // The opposite edges stay put, so the position follows the size when resizing from the
+            // left or top.
+            if drag.left {
+                position.x = drag.initial_position.x + drag.initial_size.x - size.x;
+            }
+            if drag.top {
+                position.y = drag.initial_position.y + drag.initial_size.y - size.y;
+            }
+
+            self.state.size = size;
+            // A docked window's position comes from its strip.
+            if dock.is_none() {
+                self.state.position = position;
             }
         }
 
@@ -380,12 +849,18 @@ fn show(
         let mut painter = ctx.layer_painter(layer_id);
         painter.set_clip_rect(ui.clip_rect());
 
-        let frame = Frame::window(&Style::default())
-            .inner_margin(egui::Margin::symmetric(inner_margin, inner_margin))
-            .outer_margin(egui::Margin::symmetric(outer_margin, outer_margin));
+        let frame = window_style.frame(ui.style());
         let shape = frame.paint(rect);
         painter.add(shape);
 
+        if self
+            .state
+            .keyboard_move_resize
+            .is_some()
+        {
+            painter.rect_stroke(rect, corner_radius, visuals.selection.stroke, StrokeKind::Outside);
+        }
+
         //
         // draw the window content
         //
@@ -397,14 +872,15 @@ fn show(
             UiBuilder::new()
                 .layer_id(layer_id)
                 .max_rect(content_rect)
-                .layout(Layout::top_down(Align::Min)),
+                .layout(Layout::top_down(Align::Min))
+                .accessibility_parent(accessibility_id),
         );
         window_ui.set_clip_rect(content_rect.intersect(ui_clip_rect));
 
         let window_clip_rect = window_ui.clip_rect();
         debug_rect(ui, window_clip_rect, Color32::YELLOW);
 
-        {
+        let (title_bar_response, content_response) = {
             let ui = &mut window_ui;
 
             //
@@ -420,18 +896,30 @@ fn show(
                 UiBuilder::new()
                     .layer_id(layer_id)
                     .max_rect(title_bar_rect)
-                    .sense(Sense::click_and_drag())
-                    .layout(Layout::top_down(Align::Min)),
+                    .sense(Sense::click_and_drag() - Sense::FOCUSABLE)
+                    .layout(Layout::top_down(Align::Min))
+                    .accessibility_parent(accessibility_id),
             );
 
             let title_bar_ui_rect = title_bar_rect.intersect(ui_clip_rect);
             debug_rect(ui, title_bar_ui_rect, Color32::MAGENTA);
             title_bar_rect_ui.set_clip_rect(title_bar_ui_rect);
 
+            // Being registered after the resize handles, the title bar would take precedence over
+            // the inner half of those along its top and sides, so a drag there would move the
+            // window instead of resizing it.
+            let title_bar_sense_rect = Rect::from_min_max(
+                title_bar_rect.min
+                    + vec2(
+                        if left_handle { edge_thickness } else { 0.0 },
+                        if top_handle { edge_thickness } else { 0.0 },
+                    ),
+                title_bar_rect.right_bottom() - vec2(if right_handle { edge_thickness } else { 0.0 }, 0.0),
+            );
             let title_bar_response = title_bar_rect_ui.interact(
-                title_bar_rect,
+                title_bar_sense_rect,
                 title_bar_rect_id.with("__sense"),
-                Sense::click_and_drag(),
+                Sense::click_and_drag() - Sense::FOCUSABLE,
             );
 
             // Bring the window to the front when its title bar is clicked or a drag on it starts.
@@ -448,14 +936,19 @@ fn show(
                 title_bar_rounding.sw = 0;
             }
 
-            let title_bar_color = if is_topmost {
-                visuals.widgets.active.bg_fill
-            } else {
-                visuals.widgets.open.bg_fill
-            };
+            let mut title_bar_color = window_style.title_bar_fill(&visuals, is_topmost);
+            if params.attention || tabs.iter().any(|tab| tab.attention) {
+                let time = ctx.input(|i| i.time);
+                let pulse = (0.5 - 0.5 * (time * std::f64::consts::TAU / ATTENTION_PULSE_PERIOD).cos()) as f32;
+                title_bar_color = title_bar_color.lerp_to_gamma(window_style.attention_fill(&visuals), pulse);
+                ctx.request_repaint();
+            }
 
             painter.rect_filled(title_bar_rect, title_bar_rounding, title_bar_color);
 
+            let was_collapsed = self.state.collapsed;
+            let mut toggle_maximized = false;
+
             Frame::NONE
                 .inner_margin(egui::Margin::symmetric(inner_margin, inner_margin))
                 .outer_margin(egui::Margin::symmetric(outer_margin, outer_margin))
@@ -477,46 +970,374 @@ fn show(
                             ui,
                             |ui| {
                                 ui.set_min_height(title_bar_rect.height() - border_adjust.y);
-                                collapsing_state.show_toggle_button(ui, egui::collapsing_header::paint_default_icon);
+                                let toggle_response = collapsing_state
+                                    .show_toggle_button(ui, egui::collapsing_header::paint_default_icon);
                                 self.state.collapsed = !collapsing_state.is_open();
-                                ui.label(params.title);
+                                let expanded = !self.state.collapsed;
+                                toggle_response.widget_info(|| {
+                                    WidgetInfo::labeled(
+                                        WidgetType::Button,
+                                        ui.is_enabled(),
+                                        if expanded { "Collapse" } else { "Expand" },
+                                    )
+                                });
+                                for node_id in [toggle_response.id, accessibility_id] {
+                                    ctx.accesskit_node_builder(node_id, |node| node.set_expanded(expanded));
+                                }
+                                if tabs.is_empty() {
+                                    if let Some(icon) = &params.icon {
+                                        icon.show(ui);
+                                    }
+                                    // Elided by `Sides::truncate` when it doesn't fit, in which case egui shows
+                                    // the full title when it's hovered.
+                                    ui.label(params.title);
+                                } else {
+                                    // Share the room between the tabs, so a long title is elided rather than
+                                    // pushing the other tabs out of view.
+                                    let tab_count = tabs.len() as f32;
+                                    let tab_width = ((ui.available_width()
+                                        - ui.spacing().item_spacing.x * (tab_count - 1.0))
+                                        / tab_count)
+                                        .max(0.0);
+                                    for tab in tabs {
+                                        // Elide the title rather than the icon.
+                                        let mut atoms = Atoms::new(tab.title.clone().atom_shrink(true));
+                                        if let Some(icon) = &tab.icon {
+                                            atoms.push_left(icon.atom(ui));
+                                        }
+                                        let tab_button = egui::Button::selectable(tab.id == self.id, atoms)
+                                            .sense(Sense::click_and_drag())
+                                            .truncate();
+                                        let mut response = ui
+                                            .push_id(tab.id, |ui| {
+                                                ui.allocate_ui(vec2(tab_width, ui.available_height()), |ui| {
+                                                    ui.add(tab_button)
+                                                })
+                                                .inner
+                                            })
+                                            .inner;
+                                        // Unlike a label, a button doesn't show its full text when it's elided.
+                                        if tab.is_elided(ui, response.rect.width()) {
+                                            response = response.on_hover_text(tab.title.clone());
+                                        }
+                                        tab_responses.push((tab.id, response));
+                                    }
+                                }
                             },
                             |ui| {
                                 ui.set_min_height(title_bar_rect.height() - border_adjust.y);
                                 if params.closable {
-                                    let button = egui::Button::new("X")
+                                    let button = egui::Button::new(&window_style.close_glyph)
                                         .min_size(vec2(20.0, title_bar_height))
                                         .frame(false);
 
-                                    if ui.add(button).clicked() {
+                                    let response = ui.add(button);
+                                    response.widget_info(|| {
+                                        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Close")
+                                    });
+                                    if response.clicked() {
                                         trace!("closing window: {:?}", self.id);
                                         actions.push(ToolWindowAction::CloseRequested);
                                     }
                                 }
+                                if params.maximizable && dock.is_none() {
+                                    let button = egui::Button::new(if maximized {
+                                        &window_style.restore_glyph
+                                    } else {
+                                        &window_style.maximize_glyph
+                                    })
+                                    .min_size(vec2(20.0, title_bar_height))
+                                    .frame(false);
+
+                                    let response = ui.add(button);
+                                    response.widget_info(|| {
+                                        WidgetInfo::labeled(
+                                            WidgetType::Button,
+                                            ui.is_enabled(),
+                                            if maximized { "Restore" } else { "Maximize" },
+                                        )
+                                    });
+                                    if response.clicked() {
+                                        toggle_maximized = true;
+                                    }
+                                }
+                                if params.minimizable {
+                                    let button = egui::Button::new(&window_style.minimize_glyph)
+                                        .min_size(vec2(20.0, title_bar_height))
+                                        .frame(false);
+
+                                    let response = ui.add(button);
+                                    response.widget_info(|| {
+                                        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Minimize")
+                                    });
+                                    if response.clicked() {
+                                        trace!("minimizing window: {:?}", self.id);
+                                        self.state.minimized = true;
+                                        actions.push(ToolWindowAction::Minimized);
+                                    }
+                                }
                                 if let Some(title_fn) = params.titlebar_content_fn {
                                     title_fn(ui);
                                 }
+                                // Laid out right-to-left, so these end up next to the title.
+                                if let Some(badge) = params.badge {
+                                    Frame::NONE
+                                        .fill(window_style.badge_fill(&visuals))
+                                        .corner_radius(CornerRadius::same(u8::MAX))
+                                        .inner_margin(egui::Margin::symmetric(4, 0))
+                                        .show(ui, |ui| {
+                                            ui.label(egui::RichText::new(badge).color(visuals.strong_text_color()));
+                                        });
+                                }
+                                if let Some(status) = params.status {
+                                    let size = ui.text_style_height(&egui::TextStyle::Body) / 2.0;
+                                    let (status_rect, _) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+                                    ui.painter()
+                                        .circle_filled(status_rect.center(), size / 2.0, status);
+                                }
                             },
                         );
                 });
 
+            // Right-clicking the title bar offers the window's commands, followed by the app's own.
+            let can_maximize = params.maximizable && dock.is_none();
+            let can_move_resize = anchored_rect.is_none()
+                && (params.movable || (params.fixed_size.is_none() && self.state.resizable.any()));
+            let is_bottommost = state.rendering_stack.first() == Some(&self.id);
+            let mut toggle_collapsed = false;
+            let mut start_move_resize = false;
+            let mut bring_to_front = false;
+            let mut send_to_back = false;
+            title_bar_response.context_menu(|ui| {
+                if ui
+                    .button(if self.state.collapsed { "Expand" } else { "Collapse" })
+                    .clicked()
+                {
+                    toggle_collapsed = true;
+                }
+                if ui
+                    .add_enabled(
+                        can_maximize,
+                        egui::Button::new(if maximized { "Restore" } else { "Maximize" }),
+                    )
+                    .clicked()
+                {
+                    toggle_maximized = true;
+                }
+                if ui
+                    .add_enabled(can_move_resize, egui::Button::new("Move or resize"))
+                    .on_hover_text(
+                        "Arrow keys move the window, Shift+arrow keys resize it. Enter to finish, Escape to cancel.",
+                    )
+                    .clicked()
+                {
+                    start_move_resize = true;
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(!is_topmost, egui::Button::new("Bring to front"))
+                    .clicked()
+                {
+                    bring_to_front = true;
+                }
+                if ui
+                    .add_enabled(!is_bottommost, egui::Button::new("Send to back"))
+                    .clicked()
+                {
+                    send_to_back = true;
+                }
+                ui.separator();
+                if ui
+                    .button("Reset position and size")
+                    .clicked()
+                {
+                    trace!("resetting window to default. id: {:?}", self.id);
+                    self.state.reset_requested = true;
+                    ctx.request_repaint();
+                }
+                if ui
+                    .add_enabled(params.closable, egui::Button::new("Close"))
+                    .clicked()
+                {
+                    trace!("closing window: {:?}", self.id);
+                    actions.push(ToolWindowAction::CloseRequested);
+                }
+                if let Some(context_menu_fn) = params.title_bar_context_menu_fn {
+                    ui.separator();
+                    context_menu_fn(ui);
+                }
+            });
+            if toggle_collapsed {
+                self.state.collapsed = !self.state.collapsed;
+                collapsing_state.set_open(!self.state.collapsed);
+            }
+            if start_move_resize {
+                self.start_keyboard_move_resize();
+                state.bring_to_front(self.id);
+            }
+            if bring_to_front {
+                state.bring_to_front(self.id);
+            }
+            if send_to_back {
+                state.send_to_back(self.id);
+            }
+
+            if self.state.collapsed != was_collapsed {
+                actions.push(if self.state.collapsed {
+                    ToolWindowAction::Collapsed
+                } else {
+                    ToolWindowAction::Expanded
+                });
+            }
+
+            // Clicking a tab selects it. Dragging a tab out of the title bar and dropping it splits its
+            // window off from the group, with the title bar under the pointer.
+            let pointer = ctx.input(|i| i.pointer.interact_pos());
+            let tab_grab_offset = vec2(title_bar_height, title_bar_height / 2.0);
+            for (tab_id, tab_response) in tab_responses.iter() {
+                if tab_response.clicked() && *tab_id != self.id {
+                    state.select_tab(*tab_id);
+                    state.bring_to_front(*tab_id);
+                    tab_actions.push((*tab_id, ToolWindowAction::TabSelected));
+                } else if tab_response.clicked() || tab_response.drag_started() {
+                    state.bring_to_front(self.id);
+                }
+
+                let Some(pointer) = pointer.filter(|pointer| {
+                    !title_bar_rect
+                        .expand(title_bar_height)
+                        .contains(*pointer)
+                }) else {
+                    continue;
+                };
+
+                if tab_response.dragged() {
+                    ctx.set_cursor_icon(CursorIcon::Grabbing);
+                    painter.rect_stroke(
+                        Rect::from_min_size(pointer - tab_grab_offset, rect.size()),
+                        corner_radius,
+                        visuals.selection.stroke,
+                        StrokeKind::Inside,
+                    );
+                } else if tab_response.drag_stopped() {
+                    let position = pointer - tab_grab_offset - position_origin.to_vec2();
+                    if *tab_id == self.id {
+                        self.state.position = position;
+                    } else if let Some(mut tab_window) = ToolWindow::load(&ctx, *tab_id) {
+                        tab_window.state.position = position;
+                        tab_window.store(&ctx);
+                    }
+                    state.bring_to_front(*tab_id);
+
+                    for ungrouped_id in state.ungroup(*tab_id) {
+                        if ungrouped_id == self.id {
+                            actions.push(ToolWindowAction::Ungrouped);
+                        } else {
+                            tab_actions.push((ungrouped_id, ToolWindowAction::Ungrouped));
+                        }
+                    }
+                }
+            }
+
+            if title_bar_response.double_clicked() {
+                match params.title_bar_double_click {
+                    TitleBarDoubleClick::ToggleMaximized => {
+                        toggle_maximized = can_maximize;
+                    }
+                    TitleBarDoubleClick::AutoSize => {
+                        // Fitted next frame, when the content is measured.
+                        self.state.auto_size_requested = true;
+                        ctx.request_repaint();
+                    }
+                    TitleBarDoubleClick::Nothing => {}
+                }
+            }
+            if toggle_maximized {
+                trace!("toggling maximized. id: {:?}, maximized: {:?}", self.id, !maximized);
+                self.state.maximized = !maximized;
+                actions.push(if maximized {
+                    ToolWindowAction::Unmaximized
+                } else {
+                    ToolWindowAction::Maximized
+                });
+            }
+
             // Dragging the title bar moves the window.  The input shield ensures an obscured
             // title bar can't receive a drag, so only the title bar that is actually visible at the pointer will start a move.
-            if title_bar_response.drag_started() {
+            if title_bar_response.drag_started() && params.movable && !maximized {
+                // The title bar can also be clicked, so the drag only starts once the pointer has
+                // moved a little. Pivoting on where it was pressed keeps the window under it.
                 self.state.drag_state = Some(DragState {
-                    drag_pivot: title_bar_response
-                        .interact_pointer_pos()
+                    drag_pivot: ctx
+                        .input(|i| i.pointer.press_origin())
+                        .or(title_bar_response.interact_pointer_pos())
                         .unwrap_or(top_left),
                     initial_drag_position: display_position,
-                })
-            } else if title_bar_response.drag_stopped() {
-                self.state.drag_state = None;
+                });
+                actions.push(ToolWindowAction::DragStarted);
+                if state.undock(self.id) {
+                    actions.push(ToolWindowAction::Undocked);
+                }
+            } else if title_bar_response.drag_stopped()
+                && let Some(drag_state) = self.state.drag_state.take()
+            {
+                actions.push(ToolWindowAction::DragStopped);
+                dropped_on = pointer.and_then(|pointer| {
+                    self.drop_target(state, pointer - position_origin.to_vec2(), title_bar_rect.height())
+                });
+                if docking && dropped_on.is_none() {
+                    docked_to = pointer.and_then(|pointer| DockSide::near(ui_clip_rect, pointer, DOCK_EDGE_THRESHOLD));
+                }
+                if drag_state.initial_drag_position != self.state.position {
+                    actions.push(ToolWindowAction::Moved {
+                        from: drag_state.initial_drag_position,
+                        to: self.state.position,
+                    });
+                }
             }
 
-            if let Some(drag_state) = &self.state.drag_state {
-                if let Some(pos) = ctx.input(|i| i.pointer.interact_pos()) {
-                    let delta = pos - drag_state.drag_pivot;
-                    self.state.position = drag_state.initial_drag_position + delta;
+            if let Some(drag_state) = &self.state.drag_state
+                && let Some(pos) = ctx.input(|i| i.pointer.interact_pos())
+            {
+                let delta = pos - drag_state.drag_pivot;
+                self.state.position = drag_state.initial_drag_position + delta;
+
+                if let Some(snap_targets) = &snap_targets {
+                    let moved_rect = Rect::from_min_size(self.state.position, rect.size());
+                    self.state.position += snap_targets.snap_rect(moved_rect, &mut snap_guides);
+                }
+
+                // Highlight the title bar the window would be grouped with if it was dropped now.
+                let position_pointer = pos - position_origin.to_vec2();
+                if let Some(target) = self.drop_target(state, position_pointer, title_bar_rect.height())
+                    && let Some(target_rect) = state.window_rects.get(&target)
+                {
+                    let target_title_bar_rect = Rect::from_min_size(
+                        target_rect.min + position_origin.to_vec2(),
+                        vec2(target_rect.width(), title_bar_rect.height()),
+                    );
+                    painter.rect_stroke(
+                        target_title_bar_rect,
+                        corner_radius,
+                        visuals.selection.stroke,
+                        StrokeKind::Inside,
+                    );
+                } else if docking && let Some(side) = DockSide::near(ui_clip_rect, pos, DOCK_EDGE_THRESHOLD) {
+                    // Show the strip the window would be docked to if it was dropped now.
+                    let slot = DockSlot {
+                        side,
+                        available: free_rect,
+                    };
+                    painter.rect(
+                        slot.strip(rect.size()),
+                        corner_radius,
+                        visuals
+                            .selection
+                            .bg_fill
+                            .gamma_multiply(0.25),
+                        visuals.selection.stroke,
+                        StrokeKind::Inside,
+                    );
                 }
             }
 
@@ -524,9 +1345,62 @@ fn show(
             // draw the content and resize corner
             //
 
+            let mut content_response = None;
+
             if !self.state.collapsed {
                 if let Some(content_fn) = params.content_fn {
-                    content_fn(ui);
+                    let mut content_builder = UiBuilder::new().layout(params.content_layout);
+                    if auto_sizing {
+                        // Offer the content as much room as the window could take up, so it lays out at
+                        // its desired size rather than at the window's current size.
+                        let available = free_rect.size() - border_adjust;
+                        content_builder = content_builder
+                            .max_rect(Rect::from_min_size(ui.cursor().min, params.max_size.min(available)));
+                    }
+
+                    let scroll = params.scroll;
+                    content_response = Some(
+                        ui.scope_builder(content_builder, |ui| {
+                            if scroll.any() {
+                                // Fill the window, so it can be scrolled from anywhere in it, unless it's being
+                                // fitted to the content.
+                                ScrollArea::new(scroll)
+                                    .auto_shrink(auto_sizing)
+                                    .show(ui, content_fn);
+                            } else {
+                                content_fn(ui);
+                            }
+                        })
+                        .response,
+                    );
+                }
+
+                if auto_sizing && let Some(content_response) = &content_response {
+                    let fitted = content_response.rect.max - ui.max_rect().min - border_adjust;
+                    let fitted = Self::constrain_size(
+                        fitted.max(baseline_min_size),
+                        params.min_size,
+                        params.max_size,
+                        params.keep_aspect_ratio,
+                        title_bar_height,
+                        true,
+                    );
+                    // Ignore sub-pixel differences, so rounding can't keep the window from settling.
+                    if (fitted - self.state.size)
+                        .abs()
+                        .max_elem()
+                        >= 0.5
+                    {
+                        trace!("fitting window to content. id: {:?}, size: {:?}", self.id, fitted);
+                        if auto_size_requested {
+                            actions.push(ToolWindowAction::Resized {
+                                from: self.state.size,
+                                to: fitted,
+                            });
+                        }
+                        self.state.size = fitted;
+                        ctx.request_discard("egui_tool_windows: fitting window to content");
+                    }
                 }
 
                 if needs_sizing_pass {
@@ -540,28 +1414,121 @@ fn show(
                     ctx.request_discard("egui_tool_windows: measuring content min size for resize clamp");
                 }
 
-                if let Some(corner_response) = corner_response {
-                    stolen::paint_resize_corner(ui, &corner_response);
+                if let Some((corner_response, grip_rect)) = corner_response {
+                    stolen::paint_resize_corner(ui, &corner_response, &grip_rect);
                 }
             }
-        }
+
+            (title_bar_response, content_response)
+        };
         collapsing_state.store(&ctx);
 
+        if let Some(snapping) = snapping
+            && snapping.show_guides
+        {
+            snap_guides.paint(&painter, position_origin, ui_clip_rect, visuals.selection.stroke);
+        }
+
+        // Where this window is, for other windows to snap to.
+        state
+            .window_rects
+            .insert(self.id, rect.translate(-position_origin.to_vec2()));
+
+        if !is_topmost && state.is_topmost(self.id) {
+            actions.push(ToolWindowAction::BroughtToFront);
+        }
+
+        let dragged = self.state.drag_state.is_some();
+        let resized = self.state.resize_drag_state.is_some();
+
         ToolWindowFrameResult {
-            actions,
+            response: ToolWindowResponse {
+                id: self.id,
+                rect,
+                hovered: false,
+                focused: false,
+                collapsed: self.state.collapsed,
+                minimized: self.state.minimized,
+                // Including a toggle this frame, so it agrees with any `Maximized`/`Unmaximized`
+                // action, even though `rect` is still where the window was shown.
+                maximized: self.state.maximized && dock.is_none(),
+                docked: dock.map(|slot| slot.side),
+                dragged,
+                resized,
+                title_bar_response,
+                content_response,
+                actions,
+            },
             content_space_rect,
-            dragging: self.state.drag_state.is_some() || self.state.resize_drag_state.is_some(),
+            dragging: dragged || resized,
+            dropped_on,
+            tab_responses,
+            tab_actions,
+            docked_to,
         }
     }
 
-    fn clamp_offset(available: Vec2, offset: &mut Pos2) {
-        offset.x = offset.x.clamp(0.0, available.x);
-        offset.y = offset.y.clamp(0.0, available.y);
+    /// The topmost other window whose title bar is at `pointer`, which is relative to the same
+    /// origin as `position`. Only the title bar of the topmost window at `pointer` counts, since
+    /// it hides the windows below it.
+    fn drop_target(&self, state: &ToolWindowsState, pointer: Pos2, title_bar_height: f32) -> Option<Id> {
+        state
+            .rendering_stack
+            .iter()
+            .rev()
+            .filter(|id| **id != self.id && !state.is_hidden_tab(**id))
+            .filter_map(|id| {
+                state
+                    .window_rects
+                    .get(id)
+                    .map(|rect| (*id, *rect))
+            })
+            .find(|(_, rect)| rect.contains(pointer))
+            .filter(|(_, rect)| pointer.y < rect.top() + title_bar_height)
+            .map(|(id, _)| id)
+    }
+
+    /// Keep `size` within `min` and `max` and, if given, at the aspect ratio of the content below
+    /// the title bar. When the aspect ratio is kept, the width decides the height if `x_decides`,
+    /// otherwise the height decides the width. The minimums win if they conflict with the maximums.
+    fn constrain_size(
+        size: Vec2,
+        min: Vec2,
+        max: Vec2,
+        aspect_ratio: Option<f32>,
+        title_bar_height: f32,
+        x_decides: bool,
+    ) -> Vec2 {
+        let Some(aspect_ratio) = aspect_ratio.filter(|ratio| ratio.is_finite() && *ratio > 0.0) else {
+            return size.min(max).max(min);
+        };
+
+        let height_for_width = |width: f32| width / aspect_ratio + title_bar_height;
+        let width_for_height = |height: f32| (height - title_bar_height) * aspect_ratio;
+
+        let width = if x_decides { size.x } else { width_for_height(size.y) };
+
+        // The widths that keep both the width and the height within bounds.
+        let min_width = min.x.max(width_for_height(min.y));
+        let max_width = max.x.min(width_for_height(max.y));
+        let width = width.min(max_width).max(min_width);
+
+        vec2(width, height_for_width(width))
+    }
+
+    /// Keep at least `margin` of the window inside `free`, in which the window's position must
+    /// stay.
+    fn clamp_offset(free: Rect, margin: f32, offset: &mut Pos2) {
+        offset.x = offset
+            .x
+            .clamp(free.min.x, (free.max.x - margin).max(free.min.x + margin));
+        offset.y = offset
+            .y
+            .clamp(free.min.y, (free.max.y - margin).max(free.min.y + margin));
     }
 }
 
 #[derive(Clone)]
-#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
 struct DragState {
     drag_pivot: Pos2,
     initial_drag_position: Pos2,
@@ -574,7 +1541,6 @@ struct DragState {
 /// (`pointer_pos - drag_pivot`) applied to those initial values, rather than from a per-frame
 /// delta.
 #[derive(Clone, Copy)]
-#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
 struct ResizeDragState {
     left: bool,
     right: bool,
@@ -585,16 +1551,39 @@ struct ResizeDragState {
     initial_position: Pos2,
 }
 
+/// Where the window was when it started being moved and resized with the keyboard, so it can be
+/// put back if that's cancelled, and the change reported once it's done.
+#[derive(Clone, Copy)]
+struct KeyboardMoveResize {
+    initial_position: Pos2,
+    initial_size: Vec2,
+}
+
+/// Persisted via `persistence::ToolWindowStateRecord`, which defines which fields are stored.
 #[derive(Clone)]
 #[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "persistence",
+    serde(
+        from = "persistence::ToolWindowStateRecord",
+        into = "persistence::ToolWindowStateRecord"
+    )
+)]
 struct ToolWindowState {
     collapsed: bool,
+    /// Shown as a button on the container's shelf instead of being shown itself.
+    minimized: bool,
+    /// Fills the container's visible area. `position` and `size` are kept as they were before the
+    /// window was maximized, so they can be restored.
+    maximized: bool,
     position: Pos2,
     size: Vec2,
 
+    /// `None` unless a move drag is currently in progress. Not persisted to disk.
     drag_state: Option<DragState>,
 
-    /// If false, we are no enabled
+    /// Which axes can be resized by dragging the edges/corner, mirrored from the builder's
+    /// `resizable` each frame.
     resizable: Vec2b,
 
     /// The best-known minimum size the content needs, as measured by a one-off "sizing pass"
@@ -603,18 +1592,27 @@ struct ToolWindowState {
     /// isn't being rendered and so can't be re-measured - though it will be stale if the
     /// content's requirements changed since it was last measured. Not persisted to disk: starts
     /// at `Vec2::ZERO` (no minimum enforced beyond the baseline) each time the app launches.
-    #[cfg_attr(feature = "persistence", serde(skip))]
     content_min_size: Vec2,
 
     /// Whether `content_min_size` has already been (re-)measured for the resize drag currently
     /// in progress. `false` whenever no drag is in progress, so the next drag triggers exactly
     /// one fresh measurement. Not persisted to disk.
-    #[cfg_attr(feature = "persistence", serde(skip))]
     measured_for_current_drag: bool,
 
     /// `None` unless a resize drag is currently in progress. Not persisted to disk.
-    #[cfg_attr(feature = "persistence", serde(skip))]
     resize_drag_state: Option<ResizeDragState>,
+
+    /// Fit the window to its content the next time it's shown, see
+    /// `ToolWindowsMemory::auto_size_once`. Not persisted to disk.
+    auto_size_requested: bool,
+
+    /// `None` unless the window is being moved and resized with the keyboard, see
+    /// `ToolWindowsMemory::move_resize_with_keyboard`. Not persisted to disk.
+    keyboard_move_resize: Option<KeyboardMoveResize>,
+
+    /// Show the window using its `default_pos`/`default_size` again the next time it's shown, set
+    /// from the title bar's context menu. Not persisted to disk.
+    reset_requested: bool,
 }
 
 impl Default for ToolWindowState {
@@ -622,28 +1620,35 @@ fn default() -> Self {
         Self {
             resizable: Vec2b::TRUE,
             collapsed: false,
+            minimized: false,
+            maximized: false,
             position: Pos2::ZERO,
             size: vec2(300.0, 200.0),
             drag_state: None,
             content_min_size: Vec2::ZERO,
             measured_for_current_drag: false,
             resize_drag_state: None,
+            auto_size_requested: false,
+            keyboard_move_resize: None,
+            reset_requested: false,
         }
     }
 }
 
 impl ToolWindow {
-    pub fn load_or_create_from_params(ctx: &Context, id: Id, builder: &ToolWindowParameters) -> Self {
-        Self::load(ctx, id).unwrap_or({
-            Self {
-                id,
-                state: ToolWindowState {
-                    position: builder.default_pos,
-                    size: builder.default_size,
-                    ..Default::default()
-                },
-            }
-        })
+    pub fn load_or_create_from_params(ctx: &Context, id: Id, builder: &ToolWindowParameters<'_>) -> Self {
+        Self::load(ctx, id)
+            .filter(|window| !window.state.reset_requested)
+            .unwrap_or({
+                Self {
+                    id,
+                    state: ToolWindowState {
+                        position: builder.default_pos,
+                        size: builder.default_size,
+                        ..Default::default()
+                    },
+                }
+            })
     }
 
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
@@ -659,14 +1664,97 @@ pub fn load(ctx: &Context, id: Id) -> Option<Self> {
     pub fn store(&self, ctx: &Context) {
         ctx.data_mut(|d| d.insert_persisted(self.id, self.state.clone()));
     }
+
+    /// Start moving and resizing the window with the keyboard, see `ToolWindow::show`. Cancels any
+    /// drag in progress.
+    fn start_keyboard_move_resize(&mut self) {
+        trace!("moving and resizing with the keyboard. id: {:?}", self.id);
+        self.state.drag_state = None;
+        self.state.resize_drag_state = None;
+        self.state.keyboard_move_resize = Some(KeyboardMoveResize {
+            initial_position: self.state.position,
+            initial_size: self.state.size,
+        });
+    }
+
+    /// Give another window this window's position, size and collapsed, minimized and maximized
+    /// state.
+    fn copy_geometry_to(&self, ctx: &Context, id: Id) {
+        let mut window = Self::load(ctx, id).unwrap_or_else(|| Self {
+            id,
+            state: ToolWindowState::default(),
+        });
+        window.state.position = self.state.position;
+        window.state.size = self.state.size;
+        window.state.collapsed = self.state.collapsed;
+        window.state.minimized = self.state.minimized;
+        window.state.maximized = self.state.maximized;
+        window.store(ctx);
+    }
 }
 
-#[cfg(feature = "layout_debugging")]
-fn debug_rect(ui: &mut Ui, rect: Rect, debug_color: Color32) {
-    let debug_stroke = egui::Stroke::new(1.0, debug_color);
-    ui.painter().rect(
+/// The ids used by containers and windows so far during the current pass, see `check_for_id_clash`.
+#[derive(Clone, Default)]
+struct UsedIds {
+    pass_nr: u64,
+    used: HashSet<Id>,
+    /// Ids that have been warned about already, so the log isn't flooded with one warning per frame.
+    warned: HashSet<Id>,
+}
+
+/// Warns (in the log and, when egui's `warn_on_id_clash` option is enabled, on screen) when the
+/// same container or window id is used more than once in a pass. Two containers sharing an id
+/// would fight over the stacking order, and two windows sharing an id would share their position,
+/// size and collapsed state.
+fn check_for_id_clash(ctx: &Context, id: Id, rect: Rect, what: &str) {
+    let pass_nr = ctx.cumulative_pass_nr();
+    let (clashed, first_warning) = ctx.data_mut(|d| {
+        let used_ids = d.get_temp_mut_or_default::<UsedIds>(Id::new("__egui_tool_windows_used_ids"));
+        if used_ids.pass_nr != pass_nr {
+            used_ids.pass_nr = pass_nr;
+            used_ids.used.clear();
+        }
+        let clashed = !used_ids.used.insert(id);
+        let first_warning = clashed && used_ids.warned.insert(id);
+        (clashed, first_warning)
+    });
+
+    if !clashed {
+        return;
+    }
+
+    if first_warning {
+        warn!("{} id clash, ids must be unique. id: {:?}", what, id);
+    }
+
+    if ctx.options(|options| options.warn_on_id_clash) {
+        ctx.debug_painter().debug_text(
+            rect.left_top(),
+            Align2::LEFT_TOP,
+            ctx.global_style()
+                .visuals
+                .error_fg_color,
+            format!("🔥 {} id clash: {:?}", what, id),
+        );
+    }
+}
+
+/// The cursor for a resize corner, which only resizes the axes that are enabled, so it's the
+/// `diagonal` cursor only when both are.
+fn corner_cursor(diagonal: CursorIcon, resizable: Vec2b) -> CursorIcon {
+    match (resizable.x, resizable.y) {
+        (true, false) => CursorIcon::ResizeHorizontal,
+        (false, true) => CursorIcon::ResizeVertical,
+        _ => diagonal,
+    }
+}
+
+#[cfg(feature = "layout_debugging")]
+fn debug_rect(ui: &mut Ui, rect: Rect, debug_color: Color32) {
+    let debug_stroke = egui::Stroke::new(1.0, debug_color);
+    ui.painter().rect(
         rect,
-        CornerRadius::ZERO,
+        egui::CornerRadius::ZERO,
         Color32::TRANSPARENT,
         debug_stroke,
         egui::StrokeKind::Outside,
@@ -684,9 +1772,11 @@ mod stolen {
     use egui::{Response, Ui};
 
     /// source: [`egui::containers::resize::paint_resize_corner`]
-    pub fn paint_resize_corner(ui: &Ui, response: &Response) {
+    ///
+    /// Paints in `rect`, rather than in the response's rect, which may be bigger.
+    pub fn paint_resize_corner(ui: &Ui, response: &Response, rect: &Rect) {
         let stroke = ui.style().interact(response).fg_stroke;
-        paint_resize_corner_with_style(ui, &response.rect, stroke.color, Align2::RIGHT_BOTTOM);
+        paint_resize_corner_with_style(ui, rect, stroke.color, Align2::RIGHT_BOTTOM);
     }
 
     /// source: [`egui::containers::resize::paint_resize_corner_with_style`]
@@ -716,6 +1806,15 @@ pub fn paint_resize_corner_with_style(ui: &Ui, rect: &Rect, color: impl Into<Col
 
 pub struct ToolWindows {
     scrollable: bool,
+    snapping: Option<Snapping>,
+    keyboard_navigation: Option<KeyboardNavigation>,
+    docking: bool,
+    auto_close: bool,
+    shelf_side: DockSide,
+    resize_grab_thickness: f32,
+    style: ToolWindowsStyle,
+    id: Option<Id>,
+    id_salt: Option<IdSalt>,
 }
 
 pub struct ToolWindowsStatePersistence {
@@ -723,8 +1822,16 @@ pub struct ToolWindowsStatePersistence {
     state: ToolWindowsState,
 }
 
+/// Persisted via `persistence::ToolWindowsStateRecord`, which defines which fields are stored.
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "persistence",
+    serde(
+        from = "persistence::ToolWindowsStateRecord",
+        into = "persistence::ToolWindowsStateRecord"
+    )
+)]
 pub struct ToolWindowsState {
     /// The order in which windows are rendered, the LAST one appears on TOP, the FIRST one on BOTTOM.
     rendering_stack: Vec<Id>,
@@ -741,17 +1848,42 @@ pub struct ToolWindowsState {
     /// offset down to the new, smaller max - and since window positions are anchored relative to
     /// that same offset, the window being dragged would appear to stay glued to the same screen
     /// position, no matter how far the pointer moves, until the offset bottomed out at zero.
-    #[cfg_attr(feature = "persistence", serde(skip))]
     sticky_content_extent: Option<Rect>,
 
     /// An in-progress ease from `sticky_content_extent`'s last value down to the natural extent,
     /// started the moment a drag/resize ends (see `sticky_content_extent`) if that leaves the
     /// container smaller than it was. `None` outside of that transition, in which case the
     /// natural extent is reported directly.
-    #[cfg_attr(feature = "persistence", serde(skip))]
     settling_extent: Option<SettlingExtent>,
+
+    /// Each window's rect as of the last time it was shown, relative to the same origin as its
+    /// `position`, so windows being dragged can snap to each other.
+    window_rects: HashMap<Id, Rect>,
+
+    /// Windows shown as the tabs of a single window, see `ToolWindowGroup`.
+    groups: Vec<ToolWindowGroup>,
+
+    /// Windows docked to the container's edges, in the order they were docked, i.e. the FIRST one
+    /// is nearest to its edge and gets the full length of it.
+    docked: Vec<DockedWindow>,
+
+    /// See `ToolWindows::keyboard_navigation`.
+    keyboard: KeyboardState,
+
+    /// The windows added to the builder the last time the windows were shown, in the order they
+    /// were added, including the closed ones, see `ToolWindowsMemory::windows_menu_ui`. Only the ids
+    /// of the closed windows are persisted.
+    known_windows: Vec<KnownToolWindow>,
+
+    /// The windows shown since they were last left out of the builder, see
+    /// `ToolWindowAction::FirstShown`. Not persisted, so a restored layout still reports each
+    /// window's first frame.
+    shown: HashSet<Id>,
 }
 
+/// How long, in seconds, the title bar of a window that needs attention takes to pulse once.
+const ATTENTION_PULSE_PERIOD: f64 = 1.0;
+
 /// How long `ToolWindowsState::settling_extent` takes to ease down to the natural extent.
 const CONTENT_EXTENT_SETTLE_DURATION: f32 = 0.2;
 
@@ -775,6 +1907,13 @@ pub fn bring_to_front(&mut self, id: Id) {
         self.rendering_stack.push(id);
         trace!("new rendering_stack: {:?}", self.rendering_stack);
     }
+
+    pub fn send_to_back(&mut self, id: Id) {
+        self.rendering_stack
+            .retain(|&stack_id| stack_id != id);
+        self.rendering_stack.insert(0, id);
+        trace!("new rendering_stack: {:?}", self.rendering_stack);
+    }
 }
 
 impl ToolWindowsStatePersistence {
@@ -802,13 +1941,118 @@ pub fn store(&self, ctx: &Context) {
     }
 }
 
+impl Default for ToolWindows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ToolWindows {
     pub fn new() -> Self {
         Self {
             scrollable: false,
+            snapping: None,
+            keyboard_navigation: None,
+            docking: false,
+            auto_close: false,
+            shelf_side: DockSide::Bottom,
+            resize_grab_thickness: 4.0,
+            style: ToolWindowsStyle::default(),
+            id: None,
+            id_salt: None,
         }
     }
 
+    /// How the container's windows look, see [`ToolWindowsStyle`]. Individual windows can use their
+    /// own style instead, see [`ToolWindowInstanceBuilder::style`].
+    #[inline]
+    pub fn style(mut self, style: ToolWindowsStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Snap the edges of windows being moved or resized to the container's edges, to other windows
+    /// and/or to a grid, see [`Snapping`]. Disabled by default.
+    #[inline]
+    pub fn snapping(mut self, snapping: Snapping) -> Self {
+        self.snapping = Some(snapping);
+        self
+    }
+
+    /// Switch between windows, focus their content and close them with the keyboard, see
+    /// [`KeyboardNavigation`]. Disabled by default.
+    #[inline]
+    pub fn keyboard_navigation(mut self, navigation: KeyboardNavigation) -> Self {
+        self.keyboard_navigation = Some(navigation);
+        self
+    }
+
+    /// Allow windows to be docked to the container's edges, by dragging a window's title bar to an
+    /// edge and dropping it there. A docked window fills the full length of the edge, or whatever
+    /// is left of it by windows docked before it, and the other windows are kept out of its way.
+    /// Dragging a docked window's title bar undocks it again. Disabled by default.
+    ///
+    /// Docked windows are anchored to the container's visible area, even when [`Self::scrollable`]
+    /// is used.
+    #[inline]
+    pub fn docking(mut self, docking: bool) -> Self {
+        self.docking = docking;
+        self
+    }
+
+    /// Close a window when its close button is clicked, or it's closed from its context menu or with
+    /// the keyboard, instead of leaving it to the app to stop adding it to the builder. The
+    /// `CloseRequested` action is still reported. Disabled by default.
+    ///
+    /// A closed window is left out until it's opened again, see [`ToolWindowsMemory::set_open`] and
+    /// [`ToolWindowsMemory::windows_menu_ui`], so the app can keep adding all of its windows.
+    #[inline]
+    pub fn auto_close(mut self, auto_close: bool) -> Self {
+        self.auto_close = auto_close;
+        self
+    }
+
+    /// The edge of the container that minimized windows are shown along, see
+    /// [`ToolWindowInstanceBuilder::minimizable`].
+    ///
+    /// Default: [`DockSide::Bottom`].
+    #[inline]
+    pub fn shelf_side(mut self, side: DockSide) -> Self {
+        self.shelf_side = side;
+        self
+    }
+
+    /// How far, in points, either side of a window's edges and corners the pointer can be to grab
+    /// them and resize the window. Increase it for touchscreens.
+    ///
+    /// Default: `4.0`.
+    #[inline]
+    pub fn resize_grab_thickness(mut self, thickness: f32) -> Self {
+        self.resize_grab_thickness = thickness;
+        self
+    }
+
+    /// Use this id for the container, instead of the enclosing `Ui`'s id.
+    ///
+    /// The container's state (e.g. the stacking order of its windows) is stored under this id, so
+    /// an explicit id keeps it intact when the enclosing `Ui`'s id changes, e.g. when a dock tab
+    /// is moved to a different dock node. It must be unique, a warning is logged otherwise.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Combine this salt with the enclosing `Ui`'s id to make the container's id, which is
+    /// required if you have more than one container in the same `Ui`.
+    ///
+    /// Unlike [`Self::id`], the container's id still changes along with the enclosing `Ui`'s id.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl AsIdSalt) -> Self {
+        self.id_salt = Some(IdSalt::new(id_salt));
+        self
+    }
+
     /// When `true`, windows are anchored to the container's scrollable content space (rather than
     /// its currently visible viewport) and their full extent - including any part currently
     /// clipped - is registered with the container's `Ui`. This lets an enclosing `ScrollArea` grow
@@ -821,9 +2065,23 @@ pub fn scrollable(mut self, scrollable: bool) -> Self {
         self
     }
 
-    pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<ToolWindowAction>>
+    /// Access the state of the windows in a container, to inspect or change it programmatically.
+    ///
+    /// `container_id` is the id reported in [`ToolWindowsResponse::id`].
+    pub fn memory(ctx: &Context, container_id: Id) -> ToolWindowsMemory {
+        ToolWindowsMemory::new(ctx.clone(), container_id)
+    }
+
+    fn state_id(container_id: Id) -> Id {
+        container_id.with("__tool_windows_state")
+    }
+
+    /// `collect_windows` is called once, before any window is shown, to add the windows via the
+    /// builder. The title bar and content closures are only borrowed for the duration of this
+    /// call, so they can borrow application state (e.g. `&mut self`) directly.
+    pub fn windows<'a, F>(self, ui: &mut Ui, collect_windows: F) -> ToolWindowsResponse
     where
-        F: FnMut(&mut ToolWindowsBuilder),
+        F: FnOnce(&mut ToolWindowsBuilder<'a>),
     {
         let mut builder = ToolWindowsBuilder::default();
 
@@ -831,9 +2089,33 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
         collect_windows(&mut builder);
 
         let ctx = ui.ctx().clone();
-        let state_id = ui.id().with("__tool_windows_state");
+        let container_id = match (self.id, self.id_salt) {
+            (Some(id), _) => id,
+            (None, Some(id_salt)) => ui.make_persistent_id(id_salt),
+            (None, None) => ui.id(),
+        };
+        let state_id = Self::state_id(container_id);
+
+        let container_rect = ui.max_rect();
+        check_for_id_clash(&ctx, state_id, container_rect, "ToolWindows container");
+        // Window state is stored using the window's id alone, so window ids must be unique across
+        // every container, not just within this one.
+        for (id, _) in builder.windows.iter() {
+            check_for_id_clash(&ctx, *id, container_rect, "tool window");
+        }
         let mut state_persistence = ToolWindowsStatePersistence::load_or_default(&ctx, state_id);
 
+        // Closed windows are left out, as if they hadn't been added.
+        state_persistence
+            .state
+            .update_known_windows(&builder.windows);
+        builder
+            .windows
+            .retain(|(id, _)| state_persistence.state.is_open(*id));
+
+        // windows that weren't shown before this frame, see `ToolWindowAction::FirstShown`
+        let mut new_ids: Vec<Id> = Vec::new();
+
         //
         // sync layer ordering with the id's collected
         //
@@ -855,13 +2137,46 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
                     }
                     retain
                 });
+            state_persistence
+                .state
+                .retain_group_tabs(|tab| {
+                    builder
+                        .windows
+                        .iter()
+                        .any(|(id, ..)| *id == tab)
+                });
+            let ToolWindowsState {
+                rendering_stack,
+                window_rects,
+                groups,
+                docked,
+                shown,
+                ..
+            } = &mut state_persistence.state;
+            shown.retain(|seen_id| rendering_stack.contains(seen_id));
+            docked.retain(|docked| rendering_stack.contains(&docked.id));
+            // Hidden tabs and minimized windows can't be snapped or dropped onto.
+            window_rects.retain(|id, _| {
+                rendering_stack.contains(id)
+                    && !groups
+                        .iter()
+                        .any(|group| group.active != *id && group.tabs.contains(id))
+                    && !ToolWindow::load(&ctx, *id).is_some_and(|window| window.state.minimized)
+            });
 
             // add new ids
             for (id, _) in builder.windows.iter() {
+                if state_persistence
+                    .state
+                    .shown
+                    .insert(*id)
+                {
+                    new_ids.push(*id);
+                }
                 if !state_persistence
                     .state
                     .rendering_stack
-                    .contains(&id)
+                    .contains(id)
                 {
                     trace!("adding new window. id: {:?}", id);
                     state_persistence
@@ -873,11 +2188,8 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
         }
 
         // Create a map of windows by ID for faster lookup
-        let mut windows_map: std::collections::HashMap<Id, ToolWindowParameters> = builder
-            .windows
-            .drain(..)
-            .map(|(id, params)| (id, params))
-            .collect();
+        let mut windows_map: std::collections::HashMap<Id, ToolWindowParameters<'a>> =
+            builder.windows.drain(..).collect();
 
         // The container's content-space origin - i.e. its top-left corner, not wherever the
         // cursor happens to be after any content already drawn in `ui` before this call. Windows
@@ -888,38 +2200,304 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
         // offset, which is what lets windows scroll together with the rest of the content.
         let content_origin = ui.max_rect().min;
 
-        let mut actions: HashMap<Id, Vec<ToolWindowAction>> = HashMap::new();
+        let mut responses: Vec<ToolWindowResponse> = Vec::new();
         // Every window's extent and drag status this frame, gathered so they can be aggregated
         // into a single reported extent once every window has been processed - see
         // `ToolWindowsState::sticky_content_extent` for why this can't be done per-window.
         let mut window_results: Vec<(Rect, bool)> = Vec::new();
+        // The windows that were dropped onto another window's title bar, grouped once every window
+        // has been shown, since the window dropped onto has usually been shown already.
+        let mut drops: Vec<(Id, Id)> = Vec::new();
+        let mut docks: Vec<(Id, DockSide)> = Vec::new();
+        let mut minimized_windows: Vec<MinimizedWindow> = Vec::new();
+        // Docked windows are rendered first, below the other windows, each taking its strip out of
+        // what's left of the container's visible area for the windows rendered after it.
+        let docked = state_persistence.state.docked.clone();
+        let mut free_rect = ui.clip_rect();
         // Render windows in the stored order
-        let rendering_order = state_persistence
-            .state
-            .rendering_stack
-            .clone();
+        let rendering_order: Vec<Id> = docked
+            .iter()
+            .map(|docked| docked.id)
+            .chain(
+                state_persistence
+                    .state
+                    .rendering_stack
+                    .iter()
+                    .copied()
+                    .filter(|id| {
+                        !docked
+                            .iter()
+                            .any(|docked| docked.id == *id)
+                    }),
+            )
+            .collect();
+        // Worked out up front, so a tab that's selected part way through the frame isn't shown as
+        // well as the tab it replaces.
+        let hidden_tabs: HashSet<Id> = rendering_order
+            .iter()
+            .copied()
+            .filter(|id| {
+                state_persistence
+                    .state
+                    .is_hidden_tab(*id)
+            })
+            .collect();
+
+        let keyboard_input = self
+            .keyboard_navigation
+            .as_ref()
+            .map(|navigation| {
+                let windows: Vec<SwitchableWindow> = state_persistence
+                    .state
+                    .rendering_stack
+                    .iter()
+                    .rev()
+                    .filter(|id| !hidden_tabs.contains(id))
+                    .filter_map(|id| {
+                        let params = windows_map.get(id)?;
+                        // Not yet shown windows have nothing stored, and aren't minimized or being moved.
+                        let window = ToolWindow::load(&ctx, *id);
+                        let window_state = window
+                            .as_ref()
+                            .map(|window| &window.state);
+                        if window_state.is_some_and(|state| state.minimized) {
+                            return None;
+                        }
+                        Some(SwitchableWindow {
+                            id: *id,
+                            title: params.title.clone(),
+                            icon: params.icon.clone(),
+                            closable: params.closable,
+                            moving: window_state.is_some_and(|state| state.keyboard_move_resize.is_some()),
+                        })
+                    })
+                    .collect();
+                keyboard::handle_input(
+                    ui,
+                    container_id.with("__switcher"),
+                    navigation,
+                    &mut state_persistence.state,
+                    &windows,
+                )
+            });
+        if let Some(id) = keyboard_input
+            .as_ref()
+            .and_then(|keyboard_input| keyboard_input.move_resize)
+            && let Some(mut window) = ToolWindow::load(&ctx, id)
+        {
+            window.start_keyboard_move_resize();
+            window.store(&ctx);
+        }
+        // Where each window's widgets are in the container's layer, so the topmost window's content
+        // can be given keyboard focus.
+        let mut window_widgets: Vec<(Id, Range<usize>)> = Vec::new();
+
         for id in rendering_order {
+            if hidden_tabs.contains(&id) {
+                continue;
+            }
             if let Some(params) = windows_map.remove(&id) {
                 trace!("rendering window: {:?}", id);
 
+                let tabs: Vec<Tab> = state_persistence
+                    .state
+                    .group(id)
+                    .map(|group| {
+                        group
+                            .tabs
+                            .iter()
+                            .map(|tab| {
+                                let tab_params = if *tab == id {
+                                    Some(&params)
+                                } else {
+                                    windows_map.get(tab)
+                                };
+                                Tab {
+                                    id: *tab,
+                                    attention: tab_params.is_some_and(|tab_params| tab_params.attention),
+                                    title: tab_params
+                                        .map(|tab_params| tab_params.title.clone())
+                                        .unwrap_or_default(),
+                                    icon: tab_params.and_then(|tab_params| tab_params.icon.clone()),
+                                }
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
                 let ctx = ui.ctx().clone();
                 let mut tool_window = ToolWindow::load_or_create_from_params(&ctx, id, &params);
-                ui.push_id(id.with("__tool_window"), |ui| {
-                    let result = tool_window.show(
-                        ui,
-                        params,
-                        &mut state_persistence.state,
-                        self.scrollable,
+                if tool_window.state.minimized {
+                    let mut actions = vec![];
+                    if new_ids.contains(&id) {
+                        actions.push(ToolWindowAction::FirstShown);
+                    }
+                    minimized_windows.push(MinimizedWindow {
+                        window: tool_window,
+                        title: params.title,
+                        icon: params.icon,
+                        actions,
+                    });
+                    continue;
+                }
+                // Derived from `container_id` rather than from `ui`, so any state the window content
+                // stores is kept along with the container's own state when the `ui` id changes.
+                let window_ui_builder = UiBuilder::new().id(container_id.with(id.with("__tool_window")));
+                ui.scope_builder(window_ui_builder, |ui| {
+                    let container = ContainerParameters {
+                        scrollable: self.scrollable,
                         content_origin,
-                    );
-                    if !result.actions.is_empty() {
-                        actions.insert(id, result.actions);
+                        snapping: self.snapping.as_ref(),
+                        docking: self.docking,
+                        free_rect,
+                        resize_grab_thickness: self.resize_grab_thickness,
+                        style: &self.style,
+                    };
+                    let dock = docked
+                        .iter()
+                        .find(|docked| docked.id == id)
+                        .map(|docked| DockSlot {
+                            side: docked.side,
+                            available: free_rect,
+                        });
+                    let widgets_before = keyboard_input
+                        .as_ref()
+                        .map(|_| keyboard::widget_count(&ctx, ui.layer_id()));
+                    let result = tool_window.show(ui, params, &mut state_persistence.state, &container, &tabs, dock);
+                    if let Some(widgets_before) = widgets_before {
+                        window_widgets.push((id, widgets_before..keyboard::widget_count(&ctx, ui.layer_id())));
+                    }
+                    if let Some(slot) = dock {
+                        free_rect = slot
+                            .side
+                            .split(
+                                free_rect,
+                                slot.side
+                                    .thickness(result.response.rect.size()),
+                            )
+                            .1;
+                    }
+                    let mut response = result.response;
+                    if new_ids.contains(&id) {
+                        response
+                            .actions
+                            .insert(0, ToolWindowAction::FirstShown);
+                    }
+
+                    // The group's other tabs aren't shown, but are still reported, below the active tab.
+                    for (tab_id, tab_response) in result.tab_responses {
+                        if tab_id == id {
+                            continue;
+                        }
+                        let mut actions: Vec<ToolWindowAction> = result
+                            .tab_actions
+                            .iter()
+                            .filter(|(action_id, _)| *action_id == tab_id)
+                            .map(|(_, action)| *action)
+                            .collect();
+                        if new_ids.contains(&tab_id) {
+                            actions.insert(0, ToolWindowAction::FirstShown);
+                        }
+                        responses.push(ToolWindowResponse {
+                            id: tab_id,
+                            rect: response.rect,
+                            hovered: false,
+                            focused: false,
+                            collapsed: response.collapsed,
+                            minimized: response.minimized,
+                            maximized: response.maximized,
+                            docked: response.docked,
+                            dragged: false,
+                            resized: false,
+                            title_bar_response: tab_response,
+                            content_response: None,
+                            actions,
+                        });
                     }
-                    if self.scrollable {
+
+                    // Docked and maximized windows are anchored to the viewport, not the content, so
+                    // they'd only ever make the content grow as it's scrolled.
+                    if self.scrollable && dock.is_none() && !response.maximized {
                         window_results.push((result.content_space_rect, result.dragging));
                     }
+                    responses.push(response);
+                    if let Some(target) = result.dropped_on {
+                        drops.push((id, target));
+                    }
+                    if let Some(side) = result.docked_to {
+                        docks.push((id, side));
+                    }
                 });
                 tool_window.store(&ctx);
+
+                // Keep the group's other tabs in the same place, for when they're selected.
+                if let Some(group) = state_persistence.state.group(id)
+                    && group.active == id
+                {
+                    for tab in group
+                        .tabs
+                        .iter()
+                        .filter(|tab| **tab != id)
+                    {
+                        tool_window.copy_geometry_to(&ctx, *tab);
+                    }
+                }
+            }
+        }
+
+        // Shown after the windows, so the shelf is on top of them.
+        if !minimized_windows.is_empty() {
+            responses.extend(shelf::show_shelf(
+                ui,
+                container_id.with("__shelf"),
+                self.shelf_side,
+                minimized_windows,
+                &mut state_persistence.state,
+            ));
+        }
+
+        for (id, side) in docks {
+            state_persistence.state.dock(id, side);
+            if let Some(response) = responses
+                .iter_mut()
+                .find(|response| response.id == id)
+            {
+                response
+                    .actions
+                    .push(ToolWindowAction::Docked {
+                        side,
+                    });
+            }
+        }
+
+        if let Some(keyboard_input) = &keyboard_input {
+            for (id, action) in &keyboard_input.actions {
+                if let Some(response) = responses
+                    .iter_mut()
+                    .find(|response| response.id == *id)
+                {
+                    response.actions.push(*action);
+                }
+            }
+        }
+
+        for (dropped, target) in drops {
+            // The dropped window takes the place of the window it was dropped onto.
+            if let Some(target_window) = ToolWindow::load(&ctx, target) {
+                target_window.copy_geometry_to(&ctx, dropped);
+            }
+            for grouped_id in state_persistence
+                .state
+                .group_windows(target, dropped)
+            {
+                if let Some(response) = responses
+                    .iter_mut()
+                    .find(|response| response.id == grouped_id)
+                {
+                    response
+                        .actions
+                        .push(ToolWindowAction::Grouped);
+                }
             }
         }
 
@@ -956,14 +2534,13 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
                     .state
                     .sticky_content_extent
                     .take()
+                    && natural_extent != Some(frozen)
                 {
-                    if natural_extent != Some(frozen) {
-                        state_persistence.state.settling_extent = Some(SettlingExtent {
-                            from: frozen,
-                            to: natural_extent.unwrap_or(frozen),
-                            start_time: ui.ctx().input(|i| i.time),
-                        });
-                    }
+                    state_persistence.state.settling_extent = Some(SettlingExtent {
+                        from: frozen,
+                        to: natural_extent.unwrap_or(frozen),
+                        start_time: ui.ctx().input(|i| i.time),
+                    });
                 }
 
                 if let Some(mut settling) = state_persistence.state.settling_extent {
@@ -1000,19 +2577,79 @@ pub fn windows<F>(self, ui: &mut Ui, mut collect_windows: F) -> HashMap<Id, Vec<
             }
         }
 
+        // Resolve which window, if any, is under the pointer. Windows share the container's layer,
+        // so egui's own layer-based hover can't tell them apart; walk them top-down instead and
+        // stop at the first one that contains the pointer, since it hides any below it.
+        for response in responses.iter_mut().rev() {
+            if ui.rect_contains_pointer(response.rect) {
+                response.hovered = true;
+                break;
+            }
+        }
+        for response in responses.iter_mut() {
+            response.focused = state_persistence
+                .state
+                .is_topmost(response.id);
+        }
+
+        if self.auto_close {
+            for response in responses.iter() {
+                if response
+                    .actions
+                    .contains(&ToolWindowAction::CloseRequested)
+                {
+                    state_persistence
+                        .state
+                        .set_open(response.id, false);
+                }
+            }
+        }
+
+        if let Some(keyboard_input) = keyboard_input {
+            let content_widgets = responses
+                .iter()
+                .find(|response| response.focused)
+                .and_then(|response| {
+                    let content_rect = response
+                        .content_response
+                        .as_ref()?
+                        .rect
+                        .intersect(response.rect);
+                    let (_, range) = window_widgets
+                        .iter()
+                        .find(|(id, _)| *id == response.id)?;
+                    Some(keyboard::focusable_widgets(
+                        &ctx,
+                        ui.layer_id(),
+                        range.clone(),
+                        content_rect,
+                    ))
+                })
+                .unwrap_or_default();
+            keyboard::end_frame(&ctx, &mut state_persistence.state, &keyboard_input, content_widgets);
+        }
+
         state_persistence.store(&ctx);
 
-        actions
+        let pointer_over_windows = responses
+            .iter()
+            .any(|response| response.hovered);
+
+        ToolWindowsResponse {
+            id: container_id,
+            windows: responses,
+            pointer_over_windows,
+        }
     }
 }
 
 #[derive(Default)]
-pub struct ToolWindowsBuilder {
-    windows: Vec<(Id, ToolWindowParameters)>,
+pub struct ToolWindowsBuilder<'a> {
+    windows: Vec<(Id, ToolWindowParameters<'a>)>,
 }
 
-impl ToolWindowsBuilder {
-    pub fn add_window(&mut self, id: Id) -> ToolWindowInstanceBuilder<'_> {
+impl<'a> ToolWindowsBuilder<'a> {
+    pub fn add_window(&mut self, id: Id) -> ToolWindowInstanceBuilder<'_, 'a> {
         ToolWindowInstanceBuilder {
             id,
             builder: self,
@@ -1021,23 +2658,73 @@ pub fn add_window(&mut self, id: Id) -> ToolWindowInstanceBuilder<'_> {
     }
 }
 
-pub struct ToolWindowInstanceBuilder<'a> {
+pub struct ToolWindowInstanceBuilder<'b, 'a> {
     id: Id,
-    builder: &'a mut ToolWindowsBuilder,
-    params: ToolWindowParameters,
+    builder: &'b mut ToolWindowsBuilder<'a>,
+    params: ToolWindowParameters<'a>,
 }
 
-#[derive(Default)]
-pub struct ToolWindowParameters {
-    title: String,
+type UiFn<'a> = Box<dyn FnOnce(&mut Ui) + 'a>;
+
+pub struct ToolWindowParameters<'a> {
+    title: WidgetText,
+    icon: Option<ToolWindowIcon>,
+    attention: bool,
+    status: Option<Color32>,
+    badge: Option<String>,
     closable: bool,
+    minimizable: bool,
+    maximizable: bool,
     default_pos: Pos2,
     default_size: Vec2,
-    titlebar_content_fn: Option<Box<dyn FnOnce(&mut Ui)>>,
-    content_fn: Option<Box<dyn FnOnce(&mut Ui)>>,
+    resizable: Vec2b,
+    min_size: Vec2,
+    max_size: Vec2,
+    keep_aspect_ratio: Option<f32>,
+    auto_sized: bool,
+    title_bar_double_click: TitleBarDoubleClick,
+    scroll: Vec2b,
+    content_layout: Layout,
+    style: Option<ToolWindowsStyle>,
+    movable: bool,
+    fixed_size: Option<Vec2>,
+    titlebar_content_fn: Option<UiFn<'a>>,
+    title_bar_context_menu_fn: Option<UiFn<'a>>,
+    content_fn: Option<UiFn<'a>>,
+}
+
+impl Default for ToolWindowParameters<'_> {
+    fn default() -> Self {
+        Self {
+            title: WidgetText::default(),
+            icon: None,
+            attention: false,
+            status: None,
+            badge: None,
+            closable: false,
+            minimizable: false,
+            maximizable: false,
+            default_pos: Pos2::ZERO,
+            default_size: Vec2::ZERO,
+            resizable: Vec2b::TRUE,
+            min_size: Vec2::ZERO,
+            max_size: Vec2::INFINITY,
+            keep_aspect_ratio: None,
+            auto_sized: false,
+            title_bar_double_click: TitleBarDoubleClick::ToggleMaximized,
+            scroll: Vec2b::FALSE,
+            content_layout: Layout::top_down(Align::Min),
+            style: None,
+            movable: true,
+            fixed_size: None,
+            titlebar_content_fn: None,
+            title_bar_context_menu_fn: None,
+            content_fn: None,
+        }
+    }
 }
 
-impl<'a> ToolWindowInstanceBuilder<'a> {
+impl<'a> ToolWindowInstanceBuilder<'_, 'a> {
     #[inline]
     pub fn default_pos(mut self, pos: impl Into<Pos2>) -> Self {
         self.params.default_pos = pos.into();
@@ -1056,23 +2743,309 @@ pub fn closable(mut self, closable: bool) -> Self {
         self
     }
 
+    /// Show a minimize button in the title bar. A minimized window is shown as a button on a shelf
+    /// along one of the container's edges instead, see [`ToolWindows::shelf_side`], and clicking the
+    /// button restores it to where it was.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.params.minimizable = minimizable;
+        self
+    }
+
+    /// Show a maximize button in the title bar, and maximize the window when its title bar is
+    /// double-clicked. A maximized window fills the container's visible area, less any docked
+    /// windows, until it's restored in the same way. A docked window can't be maximized.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn maximizable(mut self, maximizable: bool) -> Self {
+        self.params.maximizable = maximizable;
+        self
+    }
+
+    /// An image, or a short piece of text such as an emoji, shown before the title in the title
+    /// bar, the window's tab and its shelf button.
+    #[inline]
+    pub fn icon(mut self, icon: impl Into<ToolWindowIcon>) -> Self {
+        self.params.icon = Some(icon.into());
+        self
+    }
+
+    /// Pulse the title bar, whether or not the window is on top, e.g. to flag an error shown in
+    /// it. If the window is a tab, its group's title bar pulses. See
+    /// [`ToolWindowsStyle::attention_fill`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn attention(mut self, attention: bool) -> Self {
+        self.params.attention = attention;
+        self
+    }
+
+    /// Show a dot of this color next to the title, e.g. green when connected and red when not.
+    #[inline]
+    pub fn status(mut self, status: Color32) -> Self {
+        self.params.status = Some(status);
+        self
+    }
+
+    /// Show a short piece of text next to the title, e.g. the number of unread messages. See
+    /// [`ToolWindowsStyle::badge_fill`].
+    #[inline]
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.params.badge = Some(badge.into());
+        self
+    }
+
+    /// Which axes the window can be resized along by dragging its edges or resize corner. Ignored
+    /// if the window has a `fixed_size` or is `auto_sized`, before or after this is set.
+    ///
+    /// Default: `true` for both axes.
+    #[inline]
+    pub fn resizable(mut self, resizable: impl Into<Vec2b>) -> Self {
+        self.params.resizable = resizable.into();
+        self
+    }
+
+    /// The smallest size the user can resize the window to. The window can't be made smaller than
+    /// its content needs either way.
+    ///
+    /// Like `default_size`, this excludes the window's frame but includes the title bar.
+    #[inline]
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.params.min_size = min_size.into();
+        self
+    }
+
+    /// The largest size the user can resize the window to.
+    ///
+    /// Like `default_size`, this excludes the window's frame but includes the title bar.
+    #[inline]
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.params.max_size = max_size.into();
+        self
+    }
+
+    /// Keep the area below the title bar at this ratio of width to height while the window is
+    /// resized, e.g. `16.0 / 9.0` for a camera preview. `min_size` and `max_size` still apply.
+    #[inline]
+    pub fn keep_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.params.keep_aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Fit the window to its content every frame, like `egui::Window::auto_sized`. The user can't
+    /// resize the window, but `min_size`, `max_size` and `keep_aspect_ratio` still apply.
+    #[inline]
+    pub fn auto_sized(mut self) -> Self {
+        self.params.auto_sized = true;
+        self
+    }
+
+    /// What double-clicking the title bar does.
+    ///
+    /// Default: [`TitleBarDoubleClick::ToggleMaximized`], which does nothing unless the window is
+    /// `maximizable`.
+    #[inline]
+    pub fn title_bar_double_click(mut self, title_bar_double_click: TitleBarDoubleClick) -> Self {
+        self.params.title_bar_double_click = title_bar_double_click;
+        self
+    }
+
+    /// Show the content in a vertical scroll area, so content taller than the window can be
+    /// scrolled into view instead of being clipped.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn vscroll(mut self, vscroll: bool) -> Self {
+        self.params.scroll.y = vscroll;
+        self
+    }
+
+    /// Show the content in a horizontal scroll area, so content wider than the window can be
+    /// scrolled into view instead of being clipped.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn hscroll(mut self, hscroll: bool) -> Self {
+        self.params.scroll.x = hscroll;
+        self
+    }
+
+    /// Which axes the content can be scrolled along, see [`Self::vscroll`] and [`Self::hscroll`].
+    #[inline]
+    pub fn scroll(mut self, scroll: impl Into<Vec2b>) -> Self {
+        self.params.scroll = scroll.into();
+        self
+    }
+
+    /// How the content is laid out, e.g. `Layout::left_to_right(Align::Center)` for a horizontal
+    /// tool palette.
+    ///
+    /// Default: `Layout::top_down(Align::Min)`.
+    #[inline]
+    pub fn content_layout(mut self, content_layout: Layout) -> Self {
+        self.params.content_layout = content_layout;
+        self
+    }
+
+    /// Use this style instead of the container's, see [`ToolWindows::style`].
+    #[inline]
+    pub fn style(mut self, style: ToolWindowsStyle) -> Self {
+        self.params.style = Some(style);
+        self
+    }
+
+    /// When `false` the window can't be moved by dragging its title bar.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn movable(mut self, movable: bool) -> Self {
+        self.params.movable = movable;
+        self
+    }
+
+    /// Always use this size, and don't allow the user to resize the window.
+    ///
+    /// Any size persisted from an earlier run is ignored.
+    #[inline]
+    pub fn fixed_size(mut self, size: impl Into<Vec2>) -> Self {
+        let size = size.into();
+        self.params.default_size = size;
+        self.params.fixed_size = Some(size);
+        self
+    }
+
     pub fn titlebar_content<F>(mut self, content_fn: F) -> Self
     where
-        F: FnOnce(&mut Ui) + 'static,
+        F: FnOnce(&mut Ui) + 'a,
     {
         self.params.titlebar_content_fn = Some(Box::new(content_fn));
 
         self
     }
 
-    pub fn show<F>(mut self, title: String, content_fn: F)
+    /// Add the application's own items to the end of the title bar's context menu, after the
+    /// built-in window commands.
+    pub fn title_bar_context_menu<F>(mut self, menu_fn: F) -> Self
     where
-        F: FnOnce(&mut Ui) + 'static,
+        F: FnOnce(&mut Ui) + 'a,
     {
-        self.params.title = title;
+        self.params.title_bar_context_menu_fn = Some(Box::new(menu_fn));
+
+        self
+    }
+
+    /// Add the window. The title can be styled, e.g. `RichText::new("Camera").strong()`.
+    pub fn show<F>(mut self, title: impl Into<WidgetText>, content_fn: F)
+    where
+        F: FnOnce(&mut Ui) + 'a,
+    {
+        self.params.title = title.into();
         self.params.content_fn = Some(Box::new(content_fn));
         self.builder
             .windows
             .push((self.id, self.params));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::{Vec2, vec2};
+
+    use super::ToolWindow;
+
+    #[test]
+    fn constrain_size_within_min_and_max() {
+        let min = vec2(100.0, 100.0);
+        let max = vec2(400.0, 400.0);
+
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(50.0, 500.0), min, max, None, 20.0, true),
+            vec2(100.0, 400.0)
+        );
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(200.0, 300.0), min, max, None, 20.0, true),
+            vec2(200.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn constrain_size_to_aspect_ratio() {
+        // The aspect ratio is that of the content, below the 20 point title bar.
+        let size = vec2(400.0, 300.0);
+
+        assert_eq!(
+            ToolWindow::constrain_size(size, Vec2::ZERO, Vec2::INFINITY, Some(2.0), 20.0, true),
+            vec2(400.0, 220.0)
+        );
+        assert_eq!(
+            ToolWindow::constrain_size(size, Vec2::ZERO, Vec2::INFINITY, Some(2.0), 20.0, false),
+            vec2(560.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn constrain_size_ignores_an_invalid_aspect_ratio() {
+        let size = vec2(400.0, 300.0);
+
+        for aspect_ratio in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert_eq!(
+                ToolWindow::constrain_size(size, Vec2::ZERO, Vec2::INFINITY, Some(aspect_ratio), 20.0, true),
+                size
+            );
+        }
+    }
+
+    #[test]
+    fn constrain_size_to_aspect_ratio_and_min() {
+        // A min height of 200 means a min width of 360 at this aspect ratio.
+        let min = vec2(100.0, 200.0);
+
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(150.0, 150.0), min, Vec2::INFINITY, Some(2.0), 20.0, true),
+            vec2(360.0, 200.0)
+        );
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(150.0, 150.0), min, Vec2::INFINITY, Some(2.0), 20.0, false),
+            vec2(360.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn constrain_size_to_aspect_ratio_and_max() {
+        // A max height of 220 means a max width of 400 at this aspect ratio.
+        let max = vec2(1000.0, 220.0);
+
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(600.0, 500.0), Vec2::ZERO, max, Some(2.0), 20.0, true),
+            vec2(400.0, 220.0)
+        );
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(600.0, 500.0), Vec2::ZERO, max, Some(2.0), 20.0, false),
+            vec2(400.0, 220.0)
+        );
+    }
+
+    #[test]
+    fn constrain_size_min_wins_over_max() {
+        let min = vec2(300.0, 300.0);
+        let max = vec2(200.0, 200.0);
+
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(250.0, 250.0), min, max, None, 20.0, true),
+            min
+        );
+        // The width that satisfies the min height (560) wins over the max width.
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(250.0, 250.0), min, max, Some(2.0), 20.0, true),
+            vec2(560.0, 300.0)
+        );
+        assert_eq!(
+            ToolWindow::constrain_size(vec2(250.0, 250.0), min, max, Some(2.0), 20.0, false),
+            vec2(560.0, 300.0)
+        );
+    }
+}
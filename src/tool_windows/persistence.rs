@@ -0,0 +1,327 @@
+//! The persisted representation of `ToolWindowState` and `ToolWindowsState`.
+//!
+//! The state types themselves are (de)serialized via the records defined here, so the in-memory
+//! state can change freely without affecting what's stored in egui's memory. Each record carries
+//! a version, and converting a record into its state type is where anything stored by an older
+//! version of this crate is migrated. A record that can't be migrated would otherwise make egui
+//! silently discard the stored state, along with the user's layout.
+//!
+//! When the stored fields of a record change: bump its version, keep (as `#[serde(default)]`)
+//! whatever fields older versions need in order to be migrated, handle the previous version in
+//! the `From<..Record>` impl, and add a fixture for the previous version to the tests.
+//!
+//! Version 1 is the first released shape of each record, the one the first release after 0.7.0
+//! stores. Until that release, fields can still be added to version 1 without a bump, as long as
+//! they're `#[serde(default)]`, since the `version_1` fixtures without them must keep loading.
+
+use egui::{Id, Pos2, Vec2};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::tool_windows::docking::DockedWindow;
+use crate::tool_windows::tabs::ToolWindowGroup;
+use crate::tool_windows::{KnownToolWindow, ToolWindowState, ToolWindowsState};
+
+/// Version history:
+/// * 0 - 0.7.0 and earlier, no `version` field. Also stored `drag_state` and `resizable`, which
+///   are now ignored when loading: a drag can't survive a restart and `resizable` is re-applied
+///   from the builder every frame.
+/// * 1 - adds `version`, `minimized` and `maximized`. The first released shape, see the module
+///   docs.
+const TOOL_WINDOW_STATE_VERSION: u32 = 1;
+
+/// Version history:
+/// * 0 - 0.7.0 and earlier, no `version` field.
+/// * 1 - adds `version`, `groups`, `docked` and `closed`. The first released shape, see the
+///   module docs.
+const TOOL_WINDOWS_STATE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub(super) struct ToolWindowStateRecord {
+    /// Missing, and therefore `0`, in anything stored before versioning was introduced.
+    version: u32,
+    collapsed: bool,
+    minimized: bool,
+    maximized: bool,
+    position: Pos2,
+    size: Vec2,
+}
+
+impl Default for ToolWindowStateRecord {
+    fn default() -> Self {
+        let state = ToolWindowState::default();
+        Self {
+            version: 0,
+            collapsed: state.collapsed,
+            minimized: state.minimized,
+            maximized: state.maximized,
+            position: state.position,
+            size: state.size,
+        }
+    }
+}
+
+impl From<ToolWindowState> for ToolWindowStateRecord {
+    fn from(state: ToolWindowState) -> Self {
+        Self {
+            version: TOOL_WINDOW_STATE_VERSION,
+            collapsed: state.collapsed,
+            minimized: state.minimized,
+            maximized: state.maximized,
+            position: state.position,
+            size: state.size,
+        }
+    }
+}
+
+impl From<ToolWindowStateRecord> for ToolWindowState {
+    fn from(record: ToolWindowStateRecord) -> Self {
+        if record.version > TOOL_WINDOW_STATE_VERSION {
+            warn!(
+                "Loading tool window state from a newer version of egui_tool_windows, some of it may be lost. version: {}",
+                record.version
+            );
+        }
+
+        // Version 0 has no `minimized` or `maximized`, which default to `false`.
+        Self {
+            collapsed: record.collapsed,
+            minimized: record.minimized,
+            maximized: record.maximized,
+            position: record.position,
+            size: record.size,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(super) struct ToolWindowsStateRecord {
+    /// Missing, and therefore `0`, in anything stored before versioning was introduced.
+    version: u32,
+    rendering_stack: Vec<Id>,
+    groups: Vec<ToolWindowGroup>,
+    docked: Vec<DockedWindow>,
+    /// The known windows that are closed.
+    closed: Vec<Id>,
+}
+
+impl From<ToolWindowsState> for ToolWindowsStateRecord {
+    fn from(state: ToolWindowsState) -> Self {
+        Self {
+            version: TOOL_WINDOWS_STATE_VERSION,
+            rendering_stack: state.rendering_stack,
+            groups: state.groups,
+            docked: state.docked,
+            closed: state
+                .known_windows
+                .into_iter()
+                .filter(|window| !window.open)
+                .map(|window| window.id)
+                .collect(),
+        }
+    }
+}
+
+impl From<ToolWindowsStateRecord> for ToolWindowsState {
+    fn from(record: ToolWindowsStateRecord) -> Self {
+        if record.version > TOOL_WINDOWS_STATE_VERSION {
+            warn!(
+                "Loading tool windows state from a newer version of egui_tool_windows, some of it may be lost. version: {}",
+                record.version
+            );
+        }
+
+        // Version 0 has no groups, docked or closed windows, which is what those fields default to.
+        // The closed windows' titles are filled in when they're next added to the builder.
+        Self {
+            rendering_stack: record.rendering_stack,
+            groups: record.groups,
+            docked: record.docked,
+            known_windows: record
+                .closed
+                .into_iter()
+                .map(|id| KnownToolWindow {
+                    id,
+                    title: Default::default(),
+                    icon: None,
+                    closable: true,
+                    open: false,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Id, pos2, vec2};
+
+    use crate::DockSide;
+    use crate::tool_windows::docking::DockedWindow;
+    use crate::tool_windows::tabs::ToolWindowGroup;
+    use crate::tool_windows::{KnownToolWindow, ToolWindowParameters, ToolWindowState, ToolWindowsState};
+
+    #[test]
+    fn load_tool_window_state_0_7_0() {
+        let state: ToolWindowState = ron::from_str(include_str!("fixtures/0.7.0/tool_window_state.ron")).unwrap();
+
+        assert!(state.collapsed);
+        assert!(!state.minimized);
+        assert!(!state.maximized);
+        assert_eq!(state.position, pos2(50.0, 60.0));
+        assert_eq!(state.size, vec2(400.0, 300.0));
+    }
+
+    #[test]
+    fn load_tool_window_state_0_7_0_discards_drag_in_progress() {
+        let state: ToolWindowState =
+            ron::from_str(include_str!("fixtures/0.7.0/tool_window_state_dragging.ron")).unwrap();
+
+        assert!(!state.collapsed);
+        assert_eq!(state.position, pos2(120.0, 80.0));
+        assert_eq!(state.size, vec2(400.0, 300.0));
+        assert!(state.drag_state.is_none());
+    }
+
+    #[test]
+    fn load_tool_windows_state_0_7_0() {
+        let state: ToolWindowsState = ron::from_str(include_str!("fixtures/0.7.0/tool_windows_state.ron")).unwrap();
+
+        assert_eq!(state.rendering_stack, vec![
+            Id::new("table_tool_window_1"),
+            Id::new("table_tool_window_2")
+        ]);
+        assert!(state.groups.is_empty());
+        assert!(state.docked.is_empty());
+        assert!(state.known_windows.is_empty());
+    }
+
+    #[test]
+    fn load_tool_window_state_version_1_without_later_fields() {
+        let state: ToolWindowState = ron::from_str(include_str!("fixtures/version_1/tool_window_state.ron")).unwrap();
+
+        assert!(state.collapsed);
+        assert!(!state.minimized);
+        assert!(!state.maximized);
+        assert_eq!(state.position, pos2(50.0, 60.0));
+        assert_eq!(state.size, vec2(400.0, 300.0));
+    }
+
+    #[test]
+    fn load_tool_windows_state_version_1_without_later_fields() {
+        let state: ToolWindowsState = ron::from_str(include_str!("fixtures/version_1/tool_windows_state.ron")).unwrap();
+
+        assert_eq!(state.rendering_stack, vec![
+            Id::new("table_tool_window_1"),
+            Id::new("table_tool_window_2")
+        ]);
+        assert!(state.groups.is_empty());
+        assert!(state.docked.is_empty());
+        assert!(state.known_windows.is_empty());
+    }
+
+    #[test]
+    fn round_trip_tool_window_state() {
+        let state = ToolWindowState {
+            collapsed: true,
+            minimized: true,
+            maximized: true,
+            position: pos2(10.0, 20.0),
+            size: vec2(30.0, 40.0),
+            ..Default::default()
+        };
+
+        let stored = ron::to_string(&state).unwrap();
+        assert!(stored.contains("version:1"));
+
+        let loaded: ToolWindowState = ron::from_str(&stored).unwrap();
+        assert!(loaded.collapsed);
+        assert!(loaded.minimized);
+        assert!(loaded.maximized);
+        assert_eq!(loaded.position, state.position);
+        assert_eq!(loaded.size, state.size);
+    }
+
+    #[test]
+    fn round_trip_tool_windows_state() {
+        let state = ToolWindowsState {
+            rendering_stack: vec![Id::new("a"), Id::new("b"), Id::new("c")],
+            groups: vec![ToolWindowGroup {
+                tabs: vec![Id::new("a"), Id::new("c")],
+                active: Id::new("c"),
+            }],
+            docked: vec![DockedWindow {
+                id: Id::new("b"),
+                side: DockSide::Left,
+            }],
+            known_windows: [("a", true), ("b", true), ("c", true), ("d", false)]
+                .into_iter()
+                .map(|(id, open)| KnownToolWindow {
+                    id: Id::new(id),
+                    title: id.into(),
+                    icon: None,
+                    closable: true,
+                    open,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let stored = ron::to_string(&state).unwrap();
+        assert!(stored.contains("version:1"));
+
+        let loaded: ToolWindowsState = ron::from_str(&stored).unwrap();
+        assert_eq!(loaded.rendering_stack, state.rendering_stack);
+        assert_eq!(loaded.groups, state.groups);
+        assert_eq!(loaded.docked, state.docked);
+        // Only the closed windows are stored.
+        let known_windows: Vec<(Id, bool)> = loaded
+            .known_windows
+            .iter()
+            .map(|window| (window.id, window.open))
+            .collect();
+        assert_eq!(known_windows, vec![(Id::new("d"), false)]);
+    }
+
+    /// The windows as added to the builder, every frame.
+    fn added_windows() -> Vec<(Id, ToolWindowParameters<'static>)> {
+        ["a", "b"]
+            .into_iter()
+            .map(|id| {
+                (Id::new(id), ToolWindowParameters {
+                    title: id.into(),
+                    closable: true,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trip_closed_windows_stay_closed() {
+        let mut state = ToolWindowsState::default();
+        state.update_known_windows(&added_windows());
+        state.set_open(Id::new("b"), false);
+
+        let stored = ron::to_string(&state).unwrap();
+        let mut loaded: ToolWindowsState = ron::from_str(&stored).unwrap();
+        assert!(loaded.is_open(Id::new("a")));
+        assert!(!loaded.is_open(Id::new("b")));
+
+        // Only the closed window's id is stored, its title is filled in once it's added again.
+        loaded.update_known_windows(&added_windows());
+        let known_windows: Vec<(Id, String, bool)> = loaded
+            .known_windows
+            .iter()
+            .map(|window| (window.id, window.title.text().to_owned(), window.open))
+            .collect();
+        assert_eq!(known_windows, vec![
+            (Id::new("a"), "a".to_owned(), true),
+            (Id::new("b"), "b".to_owned(), false)
+        ]);
+    }
+}
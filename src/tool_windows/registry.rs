@@ -0,0 +1,57 @@
+use egui::{Id, WidgetText};
+use log::trace;
+
+use crate::tool_windows::{ToolWindowIcon, ToolWindowParameters, ToolWindowsState};
+
+/// A window that was added to a container the last time its windows were shown, whether it's open
+/// or not, see [`crate::ToolWindowsMemory::known_windows`].
+#[derive(Clone)]
+pub struct KnownToolWindow {
+    pub id: Id,
+    pub title: WidgetText,
+    pub icon: Option<ToolWindowIcon>,
+    /// See [`crate::ToolWindowInstanceBuilder::closable`].
+    pub closable: bool,
+    /// `false` once the window has been closed, see [`crate::ToolWindows::auto_close`] and
+    /// [`crate::ToolWindowsMemory::set_open`]. A closed window is left out, as if it hadn't been
+    /// added to the builder, until it's opened again.
+    pub open: bool,
+}
+
+impl ToolWindowsState {
+    /// Replace the known windows with the windows added to the builder this frame, keeping
+    /// whether each one is open. A window that's no longer added is forgotten, so it's open again
+    /// if it's added again later.
+    pub(crate) fn update_known_windows(&mut self, windows: &[(Id, ToolWindowParameters<'_>)]) {
+        let known_windows = windows
+            .iter()
+            .map(|(id, params)| KnownToolWindow {
+                id: *id,
+                title: params.title.clone(),
+                icon: params.icon.clone(),
+                closable: params.closable,
+                open: self.is_open(*id),
+            })
+            .collect();
+        self.known_windows = known_windows;
+    }
+
+    /// Windows that aren't known yet are open.
+    pub(crate) fn is_open(&self, id: Id) -> bool {
+        self.known_windows
+            .iter()
+            .find(|window| window.id == id)
+            .is_none_or(|window| window.open)
+    }
+
+    pub(crate) fn set_open(&mut self, id: Id, open: bool) {
+        if let Some(window) = self
+            .known_windows
+            .iter_mut()
+            .find(|window| window.id == id)
+        {
+            trace!("setting window open. id: {:?}, open: {:?}", id, open);
+            window.open = open;
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use egui::{Id, Rect, Response};
+
+use crate::{DockSide, ToolWindowAction};
+
+/// What happened to a single tool window this frame.
+#[derive(Clone, Debug)]
+pub struct ToolWindowResponse {
+    pub id: Id,
+
+    /// The window's full rect, in screen coordinates, including any part that's currently clipped
+    /// by the container.
+    pub rect: Rect,
+
+    /// The pointer is over this window, and not over another tool window that's on top of it.
+    pub hovered: bool,
+
+    /// This window is the topmost window in its container.
+    pub focused: bool,
+
+    pub collapsed: bool,
+
+    /// The window is shown as a button on the container's shelf. `rect` and `title_bar_response`
+    /// are the button's.
+    pub minimized: bool,
+
+    /// The window fills the container, see [`crate::ToolWindowInstanceBuilder::maximizable`].
+    pub maximized: bool,
+
+    /// The container edge the window is docked to, see [`crate::ToolWindows::docking`].
+    pub docked: Option<DockSide>,
+
+    /// The window is being moved by dragging its title bar.
+    pub dragged: bool,
+
+    /// The window is being resized by dragging one of its edges or its resize corner.
+    pub resized: bool,
+
+    pub title_bar_response: Response,
+
+    /// `None` while the window is collapsed, or if it has no content.
+    pub content_response: Option<Response>,
+
+    /// Actions that should be processed by the application, e.g. [`ToolWindowAction::CloseRequested`].
+    pub actions: Vec<ToolWindowAction>,
+}
+
+/// What happened to all the tool windows in a container this frame, see [`crate::ToolWindows::windows`].
+#[derive(Clone, Debug)]
+pub struct ToolWindowsResponse {
+    /// The container's id, see [`crate::ToolWindows::memory`].
+    pub id: Id,
+
+    /// One entry per window that was shown, in rendering order, i.e. the LAST one is on TOP.
+    pub windows: Vec<ToolWindowResponse>,
+
+    /// The pointer is over at least one of the tool windows, useful to suppress any interaction
+    /// (panning, zooming, etc.) the container itself would otherwise do with the pointer.
+    pub pointer_over_windows: bool,
+}
+
+impl ToolWindowsResponse {
+    pub fn window(&self, id: Id) -> Option<&ToolWindowResponse> {
+        self.windows
+            .iter()
+            .find(|window| window.id == id)
+    }
+
+    /// Every action from every window, along with the id of the window it came from.
+    pub fn actions(&self) -> impl Iterator<Item = (Id, ToolWindowAction)> + '_ {
+        self.windows.iter().flat_map(|window| {
+            window
+                .actions
+                .iter()
+                .map(|action| (window.id, *action))
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use egui::{Align, Atoms, Button, Id, Layout, Rect, Ui, UiBuilder, WidgetText, vec2};
+use log::trace;
+
+use crate::tool_windows::{
+    DockSide, ToolWindow, ToolWindowAction, ToolWindowIcon, ToolWindowResponse, ToolWindowsState,
+};
+
+/// A minimized window, shown as a button on the shelf instead of being shown itself.
+pub(crate) struct MinimizedWindow {
+    pub(crate) window: ToolWindow,
+    pub(crate) title: WidgetText,
+    pub(crate) icon: Option<ToolWindowIcon>,
+    pub(crate) actions: Vec<ToolWindowAction>,
+}
+
+/// Show a button per minimized window along the `side` of the container's visible area, on top of
+/// the windows. Clicking a button restores its window.
+pub(crate) fn show_shelf(
+    ui: &Ui,
+    id: Id,
+    side: DockSide,
+    minimized_windows: Vec<MinimizedWindow>,
+    state: &mut ToolWindowsState,
+) -> Vec<ToolWindowResponse> {
+    let ctx = ui.ctx().clone();
+    let margin = ui.spacing().item_spacing;
+    let viewport = ui.clip_rect().shrink2(margin);
+    let row_height = ui.spacing().interact_size.y;
+
+    let (rect, layout) = match side {
+        DockSide::Left => (viewport, Layout::top_down(Align::Min)),
+        DockSide::Right => (viewport, Layout::top_down(Align::Max)),
+        DockSide::Top => (
+            Rect::from_min_size(viewport.min, vec2(viewport.width(), row_height)),
+            Layout::left_to_right(Align::Center),
+        ),
+        DockSide::Bottom => (
+            Rect::from_min_size(
+                viewport.left_bottom() - vec2(0.0, row_height),
+                vec2(viewport.width(), row_height),
+            ),
+            Layout::left_to_right(Align::Center),
+        ),
+    };
+
+    // Not added to `ui`, so the shelf doesn't affect the container's size.
+    let mut shelf_ui = Ui::new(
+        ctx.clone(),
+        id,
+        UiBuilder::new()
+            .layer_id(ui.layer_id())
+            .max_rect(rect)
+            .layout(layout),
+    );
+    shelf_ui.set_clip_rect(ui.clip_rect());
+
+    minimized_windows
+        .into_iter()
+        .map(|minimized| {
+            let MinimizedWindow {
+                mut window,
+                title,
+                icon,
+                mut actions,
+            } = minimized;
+
+            let mut atoms = Atoms::new(title);
+            if let Some(icon) = icon {
+                atoms.push_left(icon.atom(&shelf_ui));
+            }
+            let button_response = shelf_ui
+                .push_id(window.id, |ui| ui.add(Button::new(atoms)))
+                .inner;
+
+            if button_response.clicked() {
+                trace!("restoring window: {:?}", window.id);
+                window.state.minimized = false;
+                window.store(&ctx);
+                state.bring_to_front(window.id);
+                actions.push(ToolWindowAction::Restored);
+            }
+
+            ToolWindowResponse {
+                id: window.id,
+                rect: button_response.rect,
+                hovered: false,
+                focused: false,
+                collapsed: window.state.collapsed,
+                minimized: window.state.minimized,
+                maximized: window.state.maximized,
+                docked: None,
+                dragged: false,
+                resized: false,
+                title_bar_response: button_response,
+                content_response: None,
+                actions,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,282 @@
+use egui::{Modifiers, Painter, Pos2, Rect, Stroke, Vec2};
+
+/// Snapping of a window's edges while it's being moved or resized, see [`crate::ToolWindows::snapping`].
+#[derive(Clone, Debug)]
+pub struct Snapping {
+    /// Snap to the edges of the container's visible area.
+    pub container_edges: bool,
+
+    /// Snap to the edges of the other windows in the container.
+    pub windows: bool,
+
+    /// Snap to a grid with this spacing, relative to the container's top-left corner.
+    pub grid: Option<f32>,
+
+    /// How close, in points, an edge has to be to something to snap to it.
+    pub threshold: f32,
+
+    /// Draw a guide line along whatever is being snapped to.
+    pub show_guides: bool,
+
+    /// Holding these modifiers while dragging temporarily disables snapping.
+    pub bypass_modifiers: Modifiers,
+}
+
+impl Default for Snapping {
+    fn default() -> Self {
+        Self {
+            container_edges: true,
+            windows: true,
+            grid: None,
+            threshold: 8.0,
+            show_guides: true,
+            bypass_modifiers: Modifiers::ALT,
+        }
+    }
+}
+
+/// Everything a window being dragged can snap to this frame.
+///
+/// Like a window's `position`, everything here is relative to the origin windows are positioned
+/// from, see `ToolWindow::show`, so it's unaffected by scrolling.
+pub(crate) struct SnapTargets {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+    grid: Option<f32>,
+    threshold: f32,
+}
+
+/// The edge(s) that snapped, in the same space as the `SnapTargets` they snapped to.
+#[derive(Default)]
+pub(crate) struct SnapGuides {
+    x: Option<f32>,
+    y: Option<f32>,
+}
+
+impl SnapTargets {
+    pub(crate) fn new(snapping: &Snapping, viewport: Rect, other_windows: impl Iterator<Item = Rect>) -> Self {
+        let mut xs = vec![];
+        let mut ys = vec![];
+
+        if snapping.container_edges {
+            xs.extend([viewport.left(), viewport.right()]);
+            ys.extend([viewport.top(), viewport.bottom()]);
+        }
+
+        if snapping.windows {
+            for rect in other_windows {
+                xs.extend([rect.left(), rect.right()]);
+                ys.extend([rect.top(), rect.bottom()]);
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            grid: snapping.grid.filter(|grid| *grid > 0.0),
+            threshold: snapping.threshold,
+        }
+    }
+
+    /// How far to move `rect` so that whichever of its edges is closest to a target (within the
+    /// threshold) lines up with it, per axis.
+    pub(crate) fn snap_rect(&self, rect: Rect, guides: &mut SnapGuides) -> Vec2 {
+        let x = self.snap_axis(&[rect.left(), rect.right()], &self.xs);
+        let y = self.snap_axis(&[rect.top(), rect.bottom()], &self.ys);
+
+        guides.x = x.map(|(_, target)| target);
+        guides.y = y.map(|(_, target)| target);
+
+        Vec2::new(x.map_or(0.0, |(offset, _)| offset), y.map_or(0.0, |(offset, _)| offset))
+    }
+
+    /// How far to move a single vertical edge at `x` so that it lines up with a target.
+    pub(crate) fn snap_x(&self, x: f32, guides: &mut SnapGuides) -> f32 {
+        let snapped = self.snap_axis(&[x], &self.xs);
+        guides.x = snapped.map(|(_, target)| target);
+        snapped.map_or(0.0, |(offset, _)| offset)
+    }
+
+    /// How far to move a single horizontal edge at `y` so that it lines up with a target.
+    pub(crate) fn snap_y(&self, y: f32, guides: &mut SnapGuides) -> f32 {
+        let snapped = self.snap_axis(&[y], &self.ys);
+        guides.y = snapped.map(|(_, target)| target);
+        snapped.map_or(0.0, |(offset, _)| offset)
+    }
+
+    /// Returns the offset to apply and the target snapped to, for the closest edge/target pair.
+    fn snap_axis(&self, edges: &[f32], targets: &[f32]) -> Option<(f32, f32)> {
+        let grid_targets = edges.iter().filter_map(|edge| {
+            self.grid
+                .map(|grid| (edge / grid).round() * grid)
+        });
+
+        targets
+            .iter()
+            .copied()
+            .chain(grid_targets)
+            .flat_map(|target| {
+                edges
+                    .iter()
+                    .map(move |edge| (target - edge, target))
+            })
+            .filter(|(offset, _)| offset.abs() <= self.threshold)
+            .min_by(|(a, _), (b, _)| a.abs().total_cmp(&b.abs()))
+    }
+}
+
+impl SnapGuides {
+    /// `origin` is the screen position of the space the guides are in, `viewport` the screen rect
+    /// the guides span.
+    pub(crate) fn paint(&self, painter: &Painter, origin: Pos2, viewport: Rect, stroke: Stroke) {
+        if let Some(x) = self.x {
+            painter.vline(origin.x + x, viewport.y_range(), stroke);
+        }
+        if let Some(y) = self.y {
+            painter.hline(viewport.x_range(), origin.y + y, stroke);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Rect, pos2, vec2};
+
+    use super::{SnapGuides, SnapTargets, Snapping};
+
+    fn viewport() -> Rect {
+        Rect::from_min_size(pos2(0.0, 0.0), vec2(1000.0, 800.0))
+    }
+
+    fn targets(snapping: &Snapping, other_windows: &[Rect]) -> SnapTargets {
+        SnapTargets::new(snapping, viewport(), other_windows.iter().copied())
+    }
+
+    #[test]
+    fn snaps_within_threshold_inclusive() {
+        let targets = targets(&Snapping::default(), &[]);
+        let mut guides = SnapGuides::default();
+
+        // Exactly `threshold` (8) away from the left edge still snaps.
+        assert_eq!(targets.snap_x(8.0, &mut guides), -8.0);
+        assert_eq!(guides.x, Some(0.0));
+
+        // Just beyond it doesn't, and clears the guide.
+        assert_eq!(targets.snap_x(8.5, &mut guides), 0.0);
+        assert_eq!(guides.x, None);
+    }
+
+    #[test]
+    fn snaps_to_the_nearest_target() {
+        let snapping = Snapping {
+            threshold: 20.0,
+            ..Default::default()
+        };
+        let other_window = Rect::from_min_max(pos2(100.0, 100.0), pos2(300.0, 300.0));
+        let targets = targets(&snapping, &[other_window]);
+        let mut guides = SnapGuides::default();
+
+        // Both of the rect's edges are within the threshold of a target: the left one is 5 from
+        // the other window's right edge, the right one 15 from the container's right edge. The
+        // closest pair decides.
+        let rect = Rect::from_min_max(pos2(305.0, 500.0), pos2(985.0, 600.0));
+        assert_eq!(targets.snap_rect(rect, &mut guides), vec2(-5.0, 0.0));
+        assert_eq!(guides.x, Some(300.0));
+        assert_eq!(guides.y, None);
+
+        // Between two targets, the closer one wins: 290 is 10 from 300 and 190 from 100.
+        assert_eq!(targets.snap_x(290.0, &mut guides), 10.0);
+        assert_eq!(guides.x, Some(300.0));
+    }
+
+    #[test]
+    fn snaps_to_the_first_target_on_a_tie() {
+        let snapping = Snapping {
+            threshold: 20.0,
+            ..Default::default()
+        };
+        // The other window's left edge is as far from 10 as the container's left edge.
+        let other_window = Rect::from_min_max(pos2(20.0, 100.0), pos2(300.0, 300.0));
+        let targets = targets(&snapping, &[other_window]);
+        let mut guides = SnapGuides::default();
+
+        assert_eq!(targets.snap_x(10.0, &mut guides), -10.0);
+        assert_eq!(guides.x, Some(0.0));
+    }
+
+    #[test]
+    fn snaps_to_container_edges_and_other_windows() {
+        let other_window = Rect::from_min_max(pos2(100.0, 100.0), pos2(300.0, 300.0));
+        let mut guides = SnapGuides::default();
+
+        // A window's edge closer than the container's edge wins.
+        let both = targets(&Snapping::default(), &[other_window]);
+        assert_eq!(both.snap_y(795.0, &mut guides), 5.0);
+        assert_eq!(guides.y, Some(800.0));
+        assert_eq!(both.snap_y(297.0, &mut guides), 3.0);
+        assert_eq!(guides.y, Some(300.0));
+
+        // Either kind of target can be turned off on its own.
+        let windows_only = targets(
+            &Snapping {
+                container_edges: false,
+                ..Default::default()
+            },
+            &[other_window],
+        );
+        assert_eq!(windows_only.snap_y(795.0, &mut guides), 0.0);
+        assert_eq!(windows_only.snap_y(297.0, &mut guides), 3.0);
+
+        let container_only = targets(
+            &Snapping {
+                windows: false,
+                ..Default::default()
+            },
+            &[other_window],
+        );
+        assert_eq!(container_only.snap_y(795.0, &mut guides), 5.0);
+        assert_eq!(container_only.snap_y(297.0, &mut guides), 0.0);
+    }
+
+    #[test]
+    fn snaps_to_the_grid() {
+        let snapping = Snapping {
+            container_edges: false,
+            windows: false,
+            grid: Some(50.0),
+            ..Default::default()
+        };
+        let targets = targets(&snapping, &[]);
+        let mut guides = SnapGuides::default();
+
+        assert_eq!(targets.snap_x(147.0, &mut guides), 3.0);
+        assert_eq!(guides.x, Some(150.0));
+        assert_eq!(targets.snap_y(306.0, &mut guides), -6.0);
+        assert_eq!(guides.y, Some(300.0));
+
+        // Halfway between grid lines is out of reach of both.
+        assert_eq!(targets.snap_x(125.0, &mut guides), 0.0);
+        assert_eq!(guides.x, None);
+
+        // Whichever edge of the rect is closest to a grid line decides.
+        let rect = Rect::from_min_max(pos2(122.0, 96.0), pos2(246.0, 180.0));
+        assert_eq!(targets.snap_rect(rect, &mut guides), vec2(4.0, 4.0));
+        assert_eq!(guides.x, Some(250.0));
+        assert_eq!(guides.y, Some(100.0));
+    }
+
+    #[test]
+    fn a_grid_without_spacing_is_ignored() {
+        let snapping = Snapping {
+            container_edges: false,
+            windows: false,
+            grid: Some(0.0),
+            ..Default::default()
+        };
+        let targets = targets(&snapping, &[]);
+        let mut guides = SnapGuides::default();
+
+        assert_eq!(targets.snap_x(3.0, &mut guides), 0.0);
+        assert_eq!(guides.x, None);
+    }
+}
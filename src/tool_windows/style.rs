@@ -0,0 +1,133 @@
+use egui::{Color32, CornerRadius, Frame, Margin, Shadow, Stroke, Style, Visuals};
+
+/// The look of a container's windows, see [`crate::ToolWindows::style`] and
+/// [`crate::ToolWindowInstanceBuilder::style`].
+///
+/// The colors, corner radius, stroke and shadow are `None` by default, which uses the
+/// [`Visuals`] of the `Ui` the container is shown in, so the windows follow the app's theme.
+#[derive(Clone, Debug)]
+pub struct ToolWindowsStyle {
+    /// The height of the title bar, excluding the window's margins.
+    pub title_bar_height: f32,
+
+    /// The space between the window's frame and its title bar and content.
+    pub inner_margin: i8,
+
+    /// The space around the window's frame.
+    pub outer_margin: i8,
+
+    /// How much of a window, in points, is kept inside the container's visible area, so it can
+    /// always be dragged back into view.
+    pub position_margin: f32,
+
+    /// Default: [`Visuals::window_corner_radius`].
+    pub corner_radius: Option<CornerRadius>,
+
+    /// The window's background. Default: [`Visuals::window_fill`].
+    pub fill: Option<Color32>,
+
+    /// Default: [`Visuals::window_stroke`].
+    pub stroke: Option<Stroke>,
+
+    /// Default: [`Visuals::window_shadow`].
+    pub shadow: Option<Shadow>,
+
+    /// The title bar of the window on top of the others. Default: the `bg_fill` of
+    /// [`Visuals::widgets`]' `active` style.
+    pub active_title_bar_fill: Option<Color32>,
+
+    /// The title bars of the other windows. Default: the `bg_fill` of [`Visuals::widgets`]' `open`
+    /// style.
+    pub inactive_title_bar_fill: Option<Color32>,
+
+    /// The color the title bar of a window that needs attention pulses towards. Default:
+    /// [`Visuals::warn_fg_color`].
+    pub attention_fill: Option<Color32>,
+
+    /// The background of a title bar's badge. Default: [`Visuals::selection`]'s `bg_fill`.
+    pub badge_fill: Option<Color32>,
+
+    /// The text of the title bar's close button.
+    pub close_glyph: String,
+
+    /// The text of the title bar's minimize button.
+    pub minimize_glyph: String,
+
+    /// The text of the title bar's maximize button.
+    pub maximize_glyph: String,
+
+    /// The text of the title bar's maximize button while the window is maximized.
+    pub restore_glyph: String,
+}
+
+impl Default for ToolWindowsStyle {
+    fn default() -> Self {
+        Self {
+            title_bar_height: 24.0,
+            inner_margin: 2,
+            outer_margin: 0,
+            position_margin: 16.0,
+            corner_radius: None,
+            fill: None,
+            stroke: None,
+            shadow: None,
+            active_title_bar_fill: None,
+            inactive_title_bar_fill: None,
+            attention_fill: None,
+            badge_fill: None,
+            close_glyph: "X".to_string(),
+            minimize_glyph: "_".to_string(),
+            maximize_glyph: "🗖".to_string(),
+            restore_glyph: "🗗".to_string(),
+        }
+    }
+}
+
+impl ToolWindowsStyle {
+    /// The total width of the margins either side of the window's content.
+    pub(crate) fn margins(&self) -> f32 {
+        (f32::from(self.inner_margin) + f32::from(self.outer_margin)) * 2.0
+    }
+
+    pub(crate) fn corner_radius(&self, visuals: &Visuals) -> CornerRadius {
+        self.corner_radius
+            .unwrap_or(visuals.window_corner_radius)
+    }
+
+    pub(crate) fn frame(&self, style: &Style) -> Frame {
+        let visuals = &style.visuals;
+        Frame::window(style)
+            .inner_margin(Margin::same(self.inner_margin))
+            .outer_margin(Margin::same(self.outer_margin))
+            .corner_radius(self.corner_radius(visuals))
+            .fill(self.fill.unwrap_or(visuals.window_fill))
+            .stroke(
+                self.stroke
+                    .unwrap_or(visuals.window_stroke),
+            )
+            .shadow(
+                self.shadow
+                    .unwrap_or(visuals.window_shadow),
+            )
+    }
+
+    pub(crate) fn title_bar_fill(&self, visuals: &Visuals, active: bool) -> Color32 {
+        if active {
+            self.active_title_bar_fill
+                .unwrap_or(visuals.widgets.active.bg_fill)
+        } else {
+            self.inactive_title_bar_fill
+                .unwrap_or(visuals.widgets.open.bg_fill)
+        }
+    }
+
+    pub(crate) fn attention_fill(&self, visuals: &Visuals) -> Color32 {
+        self.attention_fill
+            .unwrap_or(visuals.warn_fg_color)
+    }
+
+    pub(crate) fn badge_fill(&self, visuals: &Visuals) -> Color32 {
+        self.badge_fill
+            .unwrap_or(visuals.selection.bg_fill)
+    }
+}
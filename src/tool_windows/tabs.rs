@@ -0,0 +1,367 @@
+use egui::{Id, TextStyle, TextWrapMode, Ui, WidgetText};
+use log::trace;
+
+use crate::tool_windows::{ToolWindowIcon, ToolWindowsState};
+
+/// Windows that have been dropped onto each other's title bar, and are shown as the tabs of a
+/// single window, see `ToolWindowsState::groups`.
+///
+/// Only the active tab's window is shown. Its position, size and collapsed, minimized and maximized
+/// state are copied to the other tabs' windows every frame, so whichever tab is selected next
+/// appears in the same place, and minimizing or maximizing the active tab applies to the whole
+/// group.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct ToolWindowGroup {
+    /// In the order the tabs are shown in the title bar.
+    pub(crate) tabs: Vec<Id>,
+    pub(crate) active: Id,
+}
+
+/// What's shown on a tab in a group's title bar.
+pub(crate) struct Tab {
+    pub(crate) id: Id,
+    pub(crate) title: WidgetText,
+    pub(crate) icon: Option<ToolWindowIcon>,
+    /// Whether the tab's window needs attention, which makes the group's title bar pulse.
+    pub(crate) attention: bool,
+}
+
+impl Tab {
+    /// Whether a tab button of `width` is too narrow for the tab's icon and title, so the title is
+    /// elided.
+    pub(crate) fn is_elided(&self, ui: &Ui, width: f32) -> bool {
+        let spacing = ui.spacing();
+        let icon_width = self
+            .icon
+            .as_ref()
+            .map_or(0.0, |icon| icon.width(ui) + spacing.icon_spacing);
+        let title_width = self
+            .title
+            .clone()
+            .into_galley(ui, Some(TextWrapMode::Extend), f32::INFINITY, TextStyle::Button)
+            .size()
+            .x;
+
+        icon_width + title_width + spacing.button_padding.x * 2.0 > width + 0.5
+    }
+}
+
+impl ToolWindowsState {
+    pub(crate) fn group(&self, id: Id) -> Option<&ToolWindowGroup> {
+        self.groups
+            .iter()
+            .find(|group| group.tabs.contains(&id))
+    }
+
+    /// A window in a group whose tab isn't selected, and which therefore isn't shown.
+    pub(crate) fn is_hidden_tab(&self, id: Id) -> bool {
+        self.group(id)
+            .is_some_and(|group| group.active != id)
+    }
+
+    /// Add `dropped`, or the whole group if `dropped` is the active tab of one, to `target`'s group,
+    /// creating a group if `target` isn't in one yet. `dropped` becomes the active tab.
+    ///
+    /// Returns the ids of the windows that weren't in a group before.
+    pub(crate) fn group_windows(&mut self, target: Id, dropped: Id) -> Vec<Id> {
+        let mut newly_grouped = vec![];
+
+        let dropped_tabs = match self
+            .groups
+            .iter()
+            .position(|group| group.tabs.contains(&dropped))
+        {
+            Some(index) => self.groups.remove(index).tabs,
+            None => {
+                newly_grouped.push(dropped);
+                vec![dropped]
+            }
+        };
+
+        match self
+            .groups
+            .iter_mut()
+            .find(|group| group.tabs.contains(&target))
+        {
+            Some(group) => {
+                group.tabs.extend(dropped_tabs);
+                let previous_active = group.active;
+                group.active = dropped;
+                self.replace_docked(previous_active, dropped);
+            }
+            None => {
+                newly_grouped.push(target);
+                let mut tabs = vec![target];
+                tabs.extend(dropped_tabs);
+                self.groups.push(ToolWindowGroup {
+                    tabs,
+                    active: dropped,
+                });
+                self.replace_docked(target, dropped);
+            }
+        }
+
+        trace!("grouped windows. target: {:?}, dropped: {:?}", target, dropped);
+        newly_grouped
+    }
+
+    /// Remove the window from its group. A group that's left with a single tab is removed too.
+    ///
+    /// Returns the ids of the windows that are no longer in a group.
+    pub(crate) fn ungroup(&mut self, id: Id) -> Vec<Id> {
+        let Some(index) = self
+            .groups
+            .iter()
+            .position(|group| group.tabs.contains(&id))
+        else {
+            return vec![];
+        };
+
+        let group = &mut self.groups[index];
+        let tab_index = group
+            .tabs
+            .iter()
+            .position(|tab| *tab == id)
+            .unwrap();
+        group.tabs.remove(tab_index);
+        if group.active == id {
+            group.active = group.tabs[tab_index.min(group.tabs.len() - 1)];
+            let active = group.active;
+            self.replace_docked(id, active);
+        }
+
+        trace!("ungrouped window. id: {:?}", id);
+        if self.groups[index].tabs.len() < 2 {
+            let mut ungrouped = self.groups.remove(index).tabs;
+            ungrouped.insert(0, id);
+            ungrouped
+        } else {
+            vec![id]
+        }
+    }
+
+    pub(crate) fn select_tab(&mut self, id: Id) {
+        if let Some(group) = self
+            .groups
+            .iter_mut()
+            .find(|group| group.tabs.contains(&id))
+        {
+            let previous_active = group.active;
+            group.active = id;
+            self.replace_docked(previous_active, id);
+        }
+    }
+
+    /// Remove windows that are no longer in the container from their groups, and then any group
+    /// that's left with fewer than two tabs.
+    pub(crate) fn retain_group_tabs(&mut self, mut f: impl FnMut(Id) -> bool) {
+        for group in self.groups.iter_mut() {
+            let active_index = group
+                .tabs
+                .iter()
+                .position(|tab| *tab == group.active)
+                .unwrap_or(0);
+            group.tabs.retain(|tab| f(*tab));
+            if !group.tabs.contains(&group.active)
+                && let Some(active) = group
+                    .tabs
+                    .get(active_index.min(group.tabs.len().saturating_sub(1)))
+            {
+                let previous_active = group.active;
+                group.active = *active;
+                if let Some(docked) = self
+                    .docked
+                    .iter_mut()
+                    .find(|docked| docked.id == previous_active)
+                {
+                    docked.id = group.active;
+                }
+            }
+        }
+        self.groups
+            .retain(|group| group.tabs.len() >= 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Id;
+
+    use super::ToolWindowGroup;
+    use crate::DockSide;
+    use crate::tool_windows::ToolWindowsState;
+    use crate::tool_windows::docking::DockedWindow;
+
+    fn ids(names: &[&str]) -> Vec<Id> {
+        names
+            .iter()
+            .map(|name| Id::new(*name))
+            .collect()
+    }
+
+    fn group(tabs: &[&str], active: &str) -> ToolWindowGroup {
+        ToolWindowGroup {
+            tabs: ids(tabs),
+            active: Id::new(active),
+        }
+    }
+
+    #[test]
+    fn group_two_windows() {
+        let mut state = ToolWindowsState::default();
+
+        let newly_grouped = state.group_windows(Id::new("a"), Id::new("b"));
+
+        assert_eq!(newly_grouped, ids(&["b", "a"]));
+        assert_eq!(state.groups, vec![group(&["a", "b"], "b")]);
+        assert!(state.is_hidden_tab(Id::new("a")));
+        assert!(!state.is_hidden_tab(Id::new("b")));
+    }
+
+    #[test]
+    fn group_a_window_into_an_existing_group() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b"], "a")],
+            ..Default::default()
+        };
+
+        let newly_grouped = state.group_windows(Id::new("b"), Id::new("c"));
+
+        assert_eq!(newly_grouped, ids(&["c"]));
+        assert_eq!(state.groups, vec![group(&["a", "b", "c"], "c")]);
+    }
+
+    #[test]
+    fn group_a_group_into_another_group() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b"], "a"), group(&["c", "d"], "d")],
+            ..Default::default()
+        };
+
+        let newly_grouped = state.group_windows(Id::new("a"), Id::new("d"));
+
+        assert!(newly_grouped.is_empty());
+        assert_eq!(state.groups, vec![group(&["a", "b", "c", "d"], "d")]);
+    }
+
+    #[test]
+    fn group_a_docked_window_keeps_the_group_docked() {
+        let mut state = ToolWindowsState {
+            docked: vec![DockedWindow {
+                id: Id::new("a"),
+                side: DockSide::Left,
+            }],
+            ..Default::default()
+        };
+
+        state.group_windows(Id::new("a"), Id::new("b"));
+
+        assert_eq!(state.docked, vec![DockedWindow {
+            id: Id::new("b"),
+            side: DockSide::Left,
+        }]);
+    }
+
+    #[test]
+    fn ungroup_a_tab() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b", "c"], "a")],
+            ..Default::default()
+        };
+
+        let ungrouped = state.ungroup(Id::new("b"));
+
+        assert_eq!(ungrouped, ids(&["b"]));
+        assert_eq!(state.groups, vec![group(&["a", "c"], "a")]);
+    }
+
+    #[test]
+    fn ungroup_the_active_tab_selects_the_next_tab() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b", "c"], "b")],
+            ..Default::default()
+        };
+
+        state.ungroup(Id::new("b"));
+
+        assert_eq!(state.groups, vec![group(&["a", "c"], "c")]);
+
+        // Or the previous one, if it was the last tab.
+        state.group_windows(Id::new("a"), Id::new("d"));
+        state.ungroup(Id::new("d"));
+
+        assert_eq!(state.groups, vec![group(&["a", "c"], "c")]);
+    }
+
+    #[test]
+    fn ungroup_the_last_but_one_tab_removes_the_group() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b"], "b")],
+            ..Default::default()
+        };
+
+        let ungrouped = state.ungroup(Id::new("a"));
+
+        assert_eq!(ungrouped, ids(&["a", "b"]));
+        assert!(state.groups.is_empty());
+        assert!(!state.is_hidden_tab(Id::new("a")));
+        assert!(!state.is_hidden_tab(Id::new("b")));
+    }
+
+    #[test]
+    fn ungroup_a_window_that_isnt_grouped() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b"], "b")],
+            ..Default::default()
+        };
+
+        assert!(state.ungroup(Id::new("c")).is_empty());
+        assert_eq!(state.groups, vec![group(&["a", "b"], "b")]);
+    }
+
+    #[test]
+    fn close_the_active_tab() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b", "c"], "b")],
+            docked: vec![DockedWindow {
+                id: Id::new("b"),
+                side: DockSide::Bottom,
+            }],
+            ..Default::default()
+        };
+
+        state.retain_group_tabs(|id| id != Id::new("b"));
+
+        assert_eq!(state.groups, vec![group(&["a", "c"], "c")]);
+        assert_eq!(state.docked, vec![DockedWindow {
+            id: Id::new("c"),
+            side: DockSide::Bottom,
+        }]);
+    }
+
+    #[test]
+    fn close_all_but_one_tab_removes_the_group() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b", "c"], "c")],
+            ..Default::default()
+        };
+
+        state.retain_group_tabs(|id| id == Id::new("a"));
+
+        assert!(state.groups.is_empty());
+    }
+
+    #[test]
+    fn select_tab() {
+        let mut state = ToolWindowsState {
+            groups: vec![group(&["a", "b"], "a")],
+            ..Default::default()
+        };
+
+        state.select_tab(Id::new("b"));
+
+        assert_eq!(state.groups, vec![group(&["a", "b"], "b")]);
+        assert!(state.is_hidden_tab(Id::new("a")));
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::Harness;
+use egui::accesskit::{Node, NodeId, Role};
+use egui::{Id, Ui};
+use egui_tool_windows::{ToolWindows, ToolWindowsResponse};
+
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(Id::new("container"))
+        .windows(ui, |builder| {
+            builder
+                .add_window(Id::new("plain"))
+                .default_pos([50.0, 50.0])
+                .default_size([300.0, 200.0])
+                .show("Plain", |ui| {
+                    ui.label("content");
+                });
+            builder
+                .add_window(Id::new("full"))
+                .default_pos([450.0, 50.0])
+                .default_size([300.0, 200.0])
+                .closable(true)
+                .maximizable(true)
+                .minimizable(true)
+                .show("Full", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+/// Runs a frame and returns the AccessKit tree's nodes. egui sends the whole tree every frame.
+fn nodes(harness: &mut Harness) -> HashMap<NodeId, Node> {
+    let (_, output) = harness.run(vec![], show);
+    output
+        .platform_output
+        .accesskit_update
+        .expect("AccessKit is enabled")
+        .nodes
+        .into_iter()
+        .collect()
+}
+
+fn window<'a>(nodes: &'a HashMap<NodeId, Node>, title: &str) -> &'a Node {
+    nodes
+        .values()
+        .find(|node| node.role() == Role::Window && node.label() == Some(title))
+        .unwrap_or_else(|| panic!("no window node labelled {title:?}"))
+}
+
+/// The buttons anywhere below `node`, by label.
+fn buttons<'a>(nodes: &'a HashMap<NodeId, Node>, node: &'a Node) -> HashMap<&'a str, &'a Node> {
+    let mut buttons = HashMap::new();
+    let mut pending: Vec<&NodeId> = node.children().iter().collect();
+    while let Some(id) = pending.pop() {
+        let child = &nodes[id];
+        if child.role() == Role::Button
+            && let Some(label) = child.label()
+        {
+            buttons.insert(label, child);
+        }
+        pending.extend(child.children());
+    }
+    buttons
+}
+
+#[test]
+fn window_node_is_labelled_with_its_title_and_expanded_state() {
+    let mut harness = Harness::with_accesskit(show);
+
+    let expanded = nodes(&mut harness);
+    let expanded_window = window(&expanded, "Full");
+    assert_eq!(expanded_window.is_expanded(), Some(true));
+    assert_eq!(
+        buttons(&expanded, expanded_window)["Collapse"].is_expanded(),
+        Some(true)
+    );
+
+    ToolWindows::memory(&harness.ctx, Id::new("container")).set_collapsed(Id::new("full"), true);
+
+    let collapsed = nodes(&mut harness);
+    let collapsed_window = window(&collapsed, "Full");
+    assert_eq!(collapsed_window.is_expanded(), Some(false));
+    let collapsed_buttons = buttons(&collapsed, collapsed_window);
+    assert!(!collapsed_buttons.contains_key("Collapse"));
+    assert_eq!(collapsed_buttons["Expand"].is_expanded(), Some(false));
+}
+
+#[test]
+fn title_bar_buttons_are_only_there_when_enabled() {
+    let mut harness = Harness::with_accesskit(show);
+    let nodes = nodes(&mut harness);
+
+    let mut plain: Vec<&str> = buttons(&nodes, window(&nodes, "Plain"))
+        .into_keys()
+        .collect();
+    plain.sort();
+    assert_eq!(plain, vec!["Collapse"]);
+
+    let mut full: Vec<&str> = buttons(&nodes, window(&nodes, "Full"))
+        .into_keys()
+        .collect();
+    full.sort();
+    assert_eq!(full, vec!["Close", "Collapse", "Maximize", "Minimize"]);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Sense, Ui, Vec2, vec2};
+use egui_tool_windows::{ToolWindowAction, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn window_id() -> Id {
+    Id::new("window")
+}
+
+/// A window with content of `content_size`, fitted to it every frame if `auto_sized`.
+fn show(ui: &mut Ui, auto_sized: bool, content_size: Vec2) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            let mut window = builder
+                .add_window(window_id())
+                .default_pos([100.0, 100.0])
+                .default_size([300.0, 200.0]);
+            if auto_sized {
+                window = window.auto_sized();
+            }
+            window.show("Window", |ui| {
+                ui.allocate_exact_size(content_size, Sense::hover());
+            });
+        })
+}
+
+/// The window's size after showing it with content of `content_size`, along with every action
+/// reported meanwhile, including by passes egui discarded to fit the window.
+fn size_for(harness: &mut Harness, auto_sized: bool, content_size: Vec2) -> (Vec2, Vec<ToolWindowAction>) {
+    let mut actions = vec![];
+    for _ in 0..3 {
+        harness.run(vec![], |ui| {
+            let response = show(ui, auto_sized, content_size);
+            actions.extend(
+                response
+                    .actions()
+                    .map(|(_, action)| action),
+            );
+        });
+    }
+    let size = ToolWindows::memory(&harness.ctx, container_id())
+        .size(window_id())
+        .unwrap();
+    (size, actions)
+}
+
+#[test]
+fn auto_sized_windows_follow_their_content() {
+    let mut harness = Harness::new();
+
+    let (small, _) = size_for(&mut harness, true, vec2(150.0, 120.0));
+    assert_ne!(small, vec2(300.0, 200.0));
+
+    let (grown, actions) = size_for(&mut harness, true, vec2(400.0, 300.0));
+    assert_eq!(grown - small, vec2(250.0, 180.0));
+    // Only sizes the user changes are reported.
+    assert!(
+        !actions
+            .iter()
+            .any(|action| matches!(action, ToolWindowAction::Resized { .. })),
+        "{actions:?}"
+    );
+
+    let (shrunk, _) = size_for(&mut harness, true, vec2(150.0, 120.0));
+    assert_eq!(shrunk, small);
+}
+
+#[test]
+fn auto_size_once_fits_the_window_once() {
+    let mut harness = Harness::new();
+    let (unfitted, _) = size_for(&mut harness, false, vec2(150.0, 120.0));
+    assert_eq!(unfitted, vec2(300.0, 200.0));
+
+    ToolWindows::memory(&harness.ctx, container_id()).auto_size_once(window_id());
+    let (fitted, actions) = size_for(&mut harness, false, vec2(150.0, 120.0));
+    assert!(fitted.x < 300.0 && fitted.y < 200.0, "{fitted:?}");
+    assert_eq!(actions, vec![ToolWindowAction::Resized {
+        from: unfitted,
+        to: fitted
+    }]);
+
+    let (after, actions) = size_for(&mut harness, false, vec2(400.0, 300.0));
+    assert_eq!(after, fitted);
+    assert!(actions.is_empty(), "{actions:?}");
+}
@@ -0,0 +1,169 @@
+//! Runs an egui `Context` without a backend, one frame at a time, so the tests can drive tool
+//! windows with pointer and keyboard input.
+
+// Each test crate only uses some of these.
+#![allow(dead_code)]
+
+use egui::accesskit::Role;
+use egui::{Context, Event, FullOutput, Key, Modifiers, PointerButton, Pos2, RawInput, Rect, Ui, pos2, vec2};
+
+pub struct Harness {
+    pub ctx: Context,
+    time: f64,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        Self {
+            ctx: Context::default(),
+            time: 0.0,
+        }
+    }
+
+    /// A harness with AccessKit enabled that has already run a frame of `add_contents`, so the
+    /// widgets in it can be found in the AccessKit tree from the next frame on.
+    pub fn with_accesskit<R>(add_contents: impl FnMut(&mut Ui) -> R) -> Self {
+        let mut harness = Self::new();
+        harness.ctx.enable_accesskit();
+        harness.run(vec![], add_contents);
+        harness
+    }
+
+    /// Run a frame with `events`, returning whatever `add_contents` returns along with the output.
+    pub fn run<R>(&mut self, events: Vec<Event>, add_contents: impl FnMut(&mut Ui) -> R) -> (R, FullOutput) {
+        // Far enough apart that separate clicks aren't taken for a double click.
+        self.run_for(0.5, events, add_contents)
+    }
+
+    fn run_for<R>(
+        &mut self,
+        duration: f64,
+        events: Vec<Event>,
+        mut add_contents: impl FnMut(&mut Ui) -> R,
+    ) -> (R, FullOutput) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 800.0))),
+            time: Some(self.time),
+            events,
+            ..Default::default()
+        };
+        self.time += duration;
+
+        let mut result = None;
+        let mut output = self
+            .ctx
+            .run_ui(input, |ui| result = Some(add_contents(ui)));
+        // Otherwise egui complains that the textures were never freed.
+        output.textures_delta.clear();
+        (result.unwrap(), output)
+    }
+
+    /// Drag with the primary button from `from` to `to`, one frame per step, returning what
+    /// `add_contents` returned in the frame the button was released.
+    pub fn drag<R>(&mut self, from: Pos2, to: Pos2, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+        self.run(vec![Event::PointerMoved(from)], &mut add_contents);
+        self.run(vec![button(from, true)], &mut add_contents);
+        self.run(vec![Event::PointerMoved(from + (to - from) * 0.5)], &mut add_contents);
+        self.run(vec![Event::PointerMoved(to)], &mut add_contents);
+        self.run(vec![button(to, false)], &mut add_contents)
+            .0
+    }
+
+    /// `Self::drag` while holding `modifiers` down.
+    pub fn drag_holding<R>(
+        &mut self,
+        modifiers: Modifiers,
+        from: Pos2,
+        to: Pos2,
+        mut add_contents: impl FnMut(&mut Ui) -> R,
+    ) -> R {
+        self.run(vec![Event::ModifiersChanged(modifiers)], &mut add_contents);
+        let result = self.drag(from, to, &mut add_contents);
+        self.run(vec![Event::ModifiersChanged(Modifiers::NONE)], &mut add_contents);
+        result
+    }
+
+    /// Click with the primary button at `pos`.
+    pub fn click<R>(&mut self, pos: Pos2, add_contents: impl FnMut(&mut Ui) -> R) -> R {
+        self.click_with(PointerButton::Primary, pos, add_contents)
+    }
+
+    /// Click with the secondary button at `pos`, e.g. to open a context menu.
+    pub fn secondary_click<R>(&mut self, pos: Pos2, add_contents: impl FnMut(&mut Ui) -> R) -> R {
+        self.click_with(PointerButton::Secondary, pos, add_contents)
+    }
+
+    fn click_with<R>(
+        &mut self,
+        pointer_button: PointerButton,
+        pos: Pos2,
+        mut add_contents: impl FnMut(&mut Ui) -> R,
+    ) -> R {
+        let event = |pressed| Event::PointerButton {
+            pos,
+            button: pointer_button,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        self.run(vec![Event::PointerMoved(pos)], &mut add_contents);
+        self.run(vec![event(true)], &mut add_contents);
+        self.run(vec![event(false)], &mut add_contents)
+            .0
+    }
+
+    /// Click twice with the primary button at `pos`, quickly enough to be a double click.
+    pub fn double_click<R>(&mut self, pos: Pos2, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+        self.run_for(0.05, vec![Event::PointerMoved(pos)], &mut add_contents);
+        for pressed in [true, false, true] {
+            self.run_for(0.05, vec![button(pos, pressed)], &mut add_contents);
+        }
+        self.run(vec![button(pos, false)], &mut add_contents)
+            .0
+    }
+
+    /// The centre of the widget with `role` and `label` in the AccessKit tree, as of a frame of
+    /// `add_contents`.
+    pub fn find<R>(&mut self, role: Role, label: &str, add_contents: impl FnMut(&mut Ui) -> R) -> Pos2 {
+        let (_, output) = self.run(vec![], add_contents);
+        let update = output
+            .platform_output
+            .accesskit_update
+            .expect("AccessKit is enabled");
+        let bounds = update
+            .nodes
+            .iter()
+            .find(|(_, node)| node.role() == role && node.label() == Some(label))
+            .and_then(|(_, node)| node.bounds())
+            .unwrap_or_else(|| panic!("no {role:?} labelled {label:?}"));
+        pos2(
+            ((bounds.x0 + bounds.x1) / 2.0) as f32,
+            ((bounds.y0 + bounds.y1) / 2.0) as f32,
+        )
+    }
+
+    /// Press and release `key`.
+    pub fn key<R>(
+        &mut self,
+        key: Key,
+        modifiers: Modifiers,
+        add_contents: impl FnMut(&mut Ui) -> R,
+    ) -> (R, FullOutput) {
+        let event = |pressed| Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers,
+        };
+        self.run(vec![event(true), event(false)], add_contents)
+    }
+}
+
+fn button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
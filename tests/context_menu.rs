@@ -0,0 +1,210 @@
+mod common;
+
+use std::cell::Cell;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Id, Pos2, Rect, Ui, pos2, vec2};
+use egui_tool_windows::{ToolWindowAction, ToolWindowResponse, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn first_id() -> Id {
+    Id::new("First")
+}
+
+fn second_id() -> Id {
+    Id::new("Second")
+}
+
+/// Two windows side by side, the first of which adds a "Custom" item to its title bar's context
+/// menu that sets `custom_clicked`.
+fn show(ui: &mut Ui, custom_clicked: &Cell<bool>) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            builder
+                .add_window(first_id())
+                .default_pos([50.0, 50.0])
+                .default_size([300.0, 200.0])
+                .closable(true)
+                .maximizable(true)
+                .title_bar_context_menu(|ui| {
+                    if ui.button("Custom").clicked() {
+                        custom_clicked.set(true);
+                    }
+                })
+                .show("First", |ui| {
+                    ui.label("content");
+                });
+            builder
+                .add_window(second_id())
+                .default_pos([450.0, 50.0])
+                .default_size([300.0, 200.0])
+                .show("Second", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+fn window(response: &ToolWindowsResponse, id: Id) -> &ToolWindowResponse {
+    response
+        .windows
+        .iter()
+        .find(|window| window.id == id)
+        .unwrap_or_else(|| panic!("{id:?} isn't shown"))
+}
+
+struct Menu {
+    harness: Harness,
+    custom_clicked: Cell<bool>,
+    rects: Vec<(Id, Rect)>,
+}
+
+impl Menu {
+    fn new() -> Self {
+        let custom_clicked = Cell::new(false);
+        let harness = Harness::with_accesskit(|ui| show(ui, &custom_clicked));
+        let mut menu = Self {
+            harness,
+            custom_clicked,
+            rects: vec![],
+        };
+        menu.run();
+        menu
+    }
+
+    fn run(&mut self) -> ToolWindowsResponse {
+        let (response, _) = self
+            .harness
+            .run(vec![], |ui| show(ui, &self.custom_clicked));
+        self.rects = response
+            .windows
+            .iter()
+            .map(|window| (window.id, window.rect))
+            .collect();
+        response
+    }
+
+    fn title_bar(&self, id: Id) -> Pos2 {
+        let (_, rect) = self
+            .rects
+            .iter()
+            .find(|(window_id, _)| *window_id == id)
+            .unwrap();
+        rect.center_top() + vec2(0.0, 10.0)
+    }
+
+    /// Opens the window's title bar context menu and clicks `item`, returning the response of the
+    /// frame it was clicked in.
+    fn click(&mut self, id: Id, item: &str) -> ToolWindowsResponse {
+        let title_bar = self.title_bar(id);
+        self.harness
+            .secondary_click(title_bar, |ui| show(ui, &self.custom_clicked));
+        // The menu opens below the pointer, unlike the title bar's own buttons, which can have
+        // the same labels.
+        let (_, output) = self
+            .harness
+            .run(vec![], |ui| show(ui, &self.custom_clicked));
+        let bounds = output
+            .platform_output
+            .accesskit_update
+            .expect("AccessKit is enabled")
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.role() == Role::Button && node.label() == Some(item))
+            .filter_map(|(_, node)| node.bounds())
+            .find(|bounds| bounds.y0 as f32 > title_bar.y)
+            .unwrap_or_else(|| panic!("no {item:?} in the context menu"));
+        let item_pos = pos2(
+            ((bounds.x0 + bounds.x1) / 2.0) as f32,
+            ((bounds.y0 + bounds.y1) / 2.0) as f32,
+        );
+        let clicked = self
+            .harness
+            .click(item_pos, |ui| show(ui, &self.custom_clicked));
+        self.run();
+        clicked
+    }
+}
+
+#[test]
+fn collapse_collapses_the_window() {
+    let mut menu = Menu::new();
+
+    let clicked = menu.click(first_id(), "Collapse");
+
+    assert!(
+        window(&clicked, first_id())
+            .actions
+            .contains(&ToolWindowAction::Collapsed)
+    );
+    assert!(window(&menu.run(), first_id()).collapsed);
+}
+
+#[test]
+fn maximize_maximizes_the_window() {
+    let mut menu = Menu::new();
+
+    let clicked = menu.click(first_id(), "Maximize");
+
+    assert!(
+        window(&clicked, first_id())
+            .actions
+            .contains(&ToolWindowAction::Maximized)
+    );
+    assert!(window(&menu.run(), first_id()).maximized);
+}
+
+#[test]
+fn send_to_back_and_bring_to_front_restack_the_window() {
+    let mut menu = Menu::new();
+    let memory = ToolWindows::memory(&menu.harness.ctx, container_id());
+    // The second window is on top, since it was added last.
+    assert_eq!(memory.window_ids(), vec![first_id(), second_id()]);
+
+    menu.click(second_id(), "Send to back");
+    assert_eq!(memory.window_ids(), vec![second_id(), first_id()]);
+
+    menu.click(second_id(), "Bring to front");
+    assert_eq!(memory.window_ids(), vec![first_id(), second_id()]);
+}
+
+#[test]
+fn reset_puts_the_window_back_to_its_defaults() {
+    let mut menu = Menu::new();
+    let memory = ToolWindows::memory(&menu.harness.ctx, container_id());
+    memory.set_position(first_id(), pos2(120.0, 300.0));
+    memory.set_size(first_id(), vec2(250.0, 150.0));
+    menu.run();
+
+    menu.click(first_id(), "Reset position and size");
+    menu.run();
+
+    assert_eq!(memory.position(first_id()), Some(pos2(50.0, 50.0)));
+    assert_eq!(memory.size(first_id()), Some(vec2(300.0, 200.0)));
+}
+
+#[test]
+fn close_requests_closing_the_window() {
+    let mut menu = Menu::new();
+
+    let clicked = menu.click(first_id(), "Close");
+
+    assert!(
+        window(&clicked, first_id())
+            .actions
+            .contains(&ToolWindowAction::CloseRequested)
+    );
+}
+
+#[test]
+fn the_applications_own_items_are_in_the_menu() {
+    let mut menu = Menu::new();
+
+    menu.click(first_id(), "Custom");
+
+    assert!(menu.custom_clicked.get());
+}
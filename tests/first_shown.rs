@@ -0,0 +1,89 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Ui};
+use egui_tool_windows::{ToolWindowAction, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn window_id() -> Id {
+    Id::new("window")
+}
+
+/// The container with its window, or without it when `added` is false.
+fn show(ui: &mut Ui, added: bool) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            if added {
+                builder
+                    .add_window(window_id())
+                    .default_pos([200.0, 200.0])
+                    .default_size([300.0, 200.0])
+                    .show("Window", |ui| {
+                        ui.label("content");
+                    });
+            }
+        })
+}
+
+fn first_shown(response: &ToolWindowsResponse) -> bool {
+    response
+        .actions()
+        .any(|(id, action)| id == window_id() && action == ToolWindowAction::FirstShown)
+}
+
+#[test]
+fn first_shown_is_reported_once() {
+    let mut harness = Harness::new();
+
+    let (response, _) = harness.run(vec![], |ui| show(ui, true));
+    assert!(first_shown(&response));
+    let (response, _) = harness.run(vec![], |ui| show(ui, true));
+    assert!(!first_shown(&response));
+}
+
+#[test]
+fn first_shown_is_reported_again_after_being_left_out() {
+    let mut harness = Harness::new();
+    harness.run(vec![], |ui| show(ui, true));
+    harness.run(vec![], |ui| show(ui, false));
+
+    let (response, _) = harness.run(vec![], |ui| show(ui, true));
+    assert!(first_shown(&response));
+}
+
+#[test]
+fn first_shown_is_reported_for_a_window_in_an_applied_layout() {
+    let mut harness = Harness::new();
+    let layout = {
+        let mut other = Harness::new();
+        other.run(vec![], |ui| show(ui, true));
+        ToolWindows::memory(&other.ctx, container_id()).layout("saved")
+    };
+    ToolWindows::memory(&harness.ctx, container_id()).apply_layout(&layout);
+
+    let (response, _) = harness.run(vec![], |ui| show(ui, true));
+    assert!(first_shown(&response));
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn first_shown_is_reported_after_a_restart() {
+    let mut harness = Harness::new();
+    harness.run(vec![], |ui| show(ui, true));
+    let stored = harness
+        .ctx
+        .memory(ron::to_string)
+        .unwrap();
+
+    let mut restarted = Harness::new();
+    restarted
+        .ctx
+        .memory_mut(|memory| *memory = ron::from_str(&stored).unwrap());
+
+    let (response, _) = restarted.run(vec![], |ui| show(ui, true));
+    assert!(first_shown(&response));
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::Harness;
+use egui::epaint::Shape;
+use egui::{FullOutput, Id, Ui};
+use egui_tool_windows::ToolWindows;
+
+/// A container with a window per id in `window_ids`.
+fn container(ui: &mut Ui, container_id: &str, window_ids: &[&str]) {
+    ToolWindows::new()
+        .id(Id::new(container_id))
+        .windows(ui, |builder| {
+            for (index, id) in window_ids.iter().enumerate() {
+                builder
+                    .add_window(Id::new(id))
+                    .default_pos([50.0 + 200.0 * index as f32, 50.0])
+                    .default_size([150.0, 100.0])
+                    .show(*id, |ui| {
+                        ui.label("content");
+                    });
+            }
+        });
+}
+
+/// The text painted in a frame of `add_contents`, with egui's id clash warnings turned on.
+fn painted_text(add_contents: impl FnMut(&mut Ui)) -> Vec<String> {
+    let mut harness = Harness::new();
+    harness
+        .ctx
+        .options_mut(|options| options.warn_on_id_clash = true);
+    let (
+        _,
+        FullOutput {
+            shapes, ..
+        },
+    ) = harness.run(vec![], add_contents);
+    shapes
+        .into_iter()
+        .filter_map(|clipped| match clipped.shape {
+            Shape::Text(text) => Some(text.galley.text().to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn warned(text: &[String], warning: &str) -> bool {
+    text.iter()
+        .any(|text| text.contains(warning))
+}
+
+#[test]
+fn two_windows_with_the_same_id_are_warned_about() {
+    let text = painted_text(|ui| container(ui, "container", &["tools", "tools"]));
+
+    assert!(warned(&text, "tool window id clash"), "{text:?}");
+}
+
+#[test]
+fn two_containers_with_the_same_id_are_warned_about() {
+    let text = painted_text(|ui| {
+        container(ui, "container", &["first"]);
+        container(ui, "container", &["second"]);
+    });
+
+    assert!(warned(&text, "ToolWindows container id clash"), "{text:?}");
+}
+
+#[test]
+fn unique_ids_arent_warned_about() {
+    let text = painted_text(|ui| {
+        container(ui, "left", &["first", "second"]);
+        container(ui, "right", &["third"]);
+    });
+
+    assert!(!warned(&text, "id clash"), "{text:?}");
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use std::cell::RefCell;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Id, Key, Modifiers, Pos2, Ui, Vec2, vec2};
+use egui_tool_windows::{KeyboardNavigation, ToolWindowAction, ToolWindows, ToolWindowsResponse};
+
+/// Two windows with a couple of buttons each, returning the ids of each window's buttons along with
+/// the response.
+fn show(ui: &mut Ui, keyboard_navigation: bool) -> (ToolWindowsResponse, Vec<Vec<Id>>) {
+    let buttons = RefCell::new(vec![]);
+    let mut tool_windows = ToolWindows::new().id(Id::new("container"));
+    if keyboard_navigation {
+        tool_windows = tool_windows.keyboard_navigation(KeyboardNavigation::default());
+    }
+    let response = tool_windows.windows(ui, |builder| {
+        for (index, title) in ["First", "Second"]
+            .into_iter()
+            .enumerate()
+        {
+            builder
+                .add_window(Id::new(title))
+                .default_pos([50.0 + 400.0 * index as f32, 50.0])
+                .default_size([300.0, 200.0])
+                .show(title, |ui| {
+                    buttons
+                        .borrow_mut()
+                        .push(vec![ui.button("one").id, ui.button("two").id]);
+                });
+        }
+    });
+    (response, buttons.into_inner())
+}
+
+/// Presses Tab enough times to go round every focusable widget twice, returning whatever had
+/// keyboard focus after each press, and its AccessKit role, along with the ids of each window's
+/// buttons.
+fn tab_around(keyboard_navigation: bool) -> (Vec<(Id, Role)>, Vec<Vec<Id>>) {
+    let mut harness = Harness::with_accesskit(|ui| show(ui, keyboard_navigation));
+    let ((_, buttons), _) = harness.run(vec![], |ui| show(ui, keyboard_navigation));
+
+    let focused = (0..16)
+        .filter_map(|_| {
+            let (_, output) = harness.key(Key::Tab, Modifiers::NONE, |ui| show(ui, keyboard_navigation));
+            let focused = harness
+                .ctx
+                .memory(|memory| memory.focused())?;
+            let update = output
+                .platform_output
+                .accesskit_update
+                .unwrap();
+            let role = update
+                .nodes
+                .iter()
+                .find(|(node_id, _)| *node_id == focused.accesskit_id())
+                .map(|(_, node)| node.role())
+                .unwrap();
+            Some((focused, role))
+        })
+        .collect();
+    (focused, buttons)
+}
+
+#[test]
+fn tab_only_focuses_buttons() {
+    let (focused, buttons) = tab_around(false);
+
+    for (id, role) in &focused {
+        assert_eq!(*role, Role::Button, "focused {id:?}");
+    }
+    // Including every window's content.
+    for id in buttons.iter().flatten() {
+        assert!(
+            focused
+                .iter()
+                .any(|(focused, _)| focused == id),
+            "never focused {id:?}"
+        );
+    }
+}
+
+#[test]
+fn tab_with_keyboard_navigation_only_focuses_the_topmost_windows_content() {
+    let (focused, buttons) = tab_around(true);
+
+    // The second window is on top, since it was added last.
+    let topmost_buttons = &buttons[1];
+    for (id, role) in &focused {
+        assert_eq!(*role, Role::Button, "focused {id:?}");
+        assert!(topmost_buttons.contains(id), "focused {id:?}");
+    }
+    for id in topmost_buttons {
+        assert!(
+            focused
+                .iter()
+                .any(|(focused, _)| focused == id),
+            "never focused {id:?}"
+        );
+    }
+}
+
+/// Starts moving and resizing the topmost window with the keyboard shortcut, returning its
+/// position and size beforehand.
+fn start_move_resize(harness: &mut Harness) -> (Pos2, Vec2) {
+    harness.run(vec![], |ui| show(ui, true));
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    let before = (memory.position(second_id()).unwrap(), memory.size(second_id()).unwrap());
+    harness.key(Key::F7, Modifiers::COMMAND, |ui| show(ui, true));
+    before
+}
+
+fn second_id() -> Id {
+    Id::new("Second")
+}
+
+/// Presses `key` `times` times, returning the actions of every frame.
+fn press(harness: &mut Harness, key: Key, modifiers: Modifiers, times: usize) -> Vec<ToolWindowAction> {
+    (0..times)
+        .flat_map(|_| {
+            let ((response, _), _) = harness.key(key, modifiers, |ui| show(ui, true));
+            response
+                .actions()
+                .map(|(_, action)| action)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn arrows_move_the_window() {
+    let mut harness = Harness::new();
+    let (position, size) = start_move_resize(&mut harness);
+
+    press(&mut harness, Key::ArrowRight, Modifiers::NONE, 3);
+    press(&mut harness, Key::ArrowDown, Modifiers::COMMAND, 2);
+
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    assert_eq!(memory.position(second_id()), Some(position + vec2(3.0, 20.0)));
+    assert_eq!(memory.size(second_id()), Some(size));
+}
+
+#[test]
+fn shift_arrows_resize_the_window() {
+    let mut harness = Harness::new();
+    let (position, size) = start_move_resize(&mut harness);
+
+    press(&mut harness, Key::ArrowRight, Modifiers::SHIFT, 3);
+    press(&mut harness, Key::ArrowUp, Modifiers::SHIFT | Modifiers::COMMAND, 2);
+
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    assert_eq!(memory.position(second_id()), Some(position));
+    assert_eq!(memory.size(second_id()), Some(size + vec2(3.0, -20.0)));
+}
+
+#[test]
+fn escape_puts_the_window_back() {
+    let mut harness = Harness::new();
+    let (position, size) = start_move_resize(&mut harness);
+
+    press(&mut harness, Key::ArrowRight, Modifiers::COMMAND, 2);
+    press(&mut harness, Key::ArrowDown, Modifiers::SHIFT | Modifiers::COMMAND, 2);
+    let actions = press(&mut harness, Key::Escape, Modifiers::NONE, 1);
+
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    assert_eq!(memory.position(second_id()), Some(position));
+    assert_eq!(memory.size(second_id()), Some(size));
+    assert!(actions.is_empty(), "{actions:?}");
+
+    // It's over, so the arrows are left alone.
+    press(&mut harness, Key::ArrowRight, Modifiers::NONE, 1);
+    assert_eq!(memory.position(second_id()), Some(position));
+}
+
+#[test]
+fn enter_keeps_the_change_and_reports_it() {
+    let mut harness = Harness::new();
+    let (position, size) = start_move_resize(&mut harness);
+
+    let during = press(&mut harness, Key::ArrowLeft, Modifiers::COMMAND, 2);
+    press(&mut harness, Key::ArrowDown, Modifiers::SHIFT | Modifiers::COMMAND, 1);
+    let actions = press(&mut harness, Key::Enter, Modifiers::NONE, 1);
+
+    assert!(during.is_empty(), "{during:?}");
+    let moved_to = position - vec2(20.0, 0.0);
+    let resized_to = size + vec2(0.0, 10.0);
+    assert_eq!(actions, vec![
+        ToolWindowAction::Resized {
+            from: size,
+            to: resized_to
+        },
+        ToolWindowAction::Moved {
+            from: position,
+            to: moved_to
+        },
+    ]);
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    assert_eq!(memory.position(second_id()), Some(moved_to));
+    assert_eq!(memory.size(second_id()), Some(resized_to));
+
+    press(&mut harness, Key::ArrowRight, Modifiers::NONE, 1);
+    assert_eq!(memory.position(second_id()), Some(moved_to));
+}
+
+#[test]
+fn moving_with_the_keyboard_is_clamped_to_the_container() {
+    let mut harness = Harness::new();
+    start_move_resize(&mut harness);
+
+    // Far further than the window is from the container's left and top edges.
+    press(&mut harness, Key::ArrowLeft, Modifiers::COMMAND, 60);
+    press(&mut harness, Key::ArrowUp, Modifiers::COMMAND, 10);
+
+    let memory = ToolWindows::memory(&harness.ctx, Id::new("container"));
+    assert_eq!(memory.position(second_id()), Some(Pos2::ZERO));
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Ui, pos2, vec2};
+use egui_tool_windows::{ToolWindowLayout, ToolWindows, ToolWindowsLayout, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+/// Three windows, plus a fourth one when `later` is true.
+fn show(ui: &mut Ui, later: bool) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            let titles: &[&str] = if later {
+                &["a", "b", "c", "later"]
+            } else {
+                &["a", "b", "c"]
+            };
+            for (index, title) in titles.iter().enumerate() {
+                builder
+                    .add_window(Id::new(title))
+                    .default_pos([20.0 + 200.0 * index as f32, 20.0])
+                    .default_size([150.0, 100.0])
+                    .show(*title, |ui| {
+                        ui.label("content");
+                    });
+            }
+        })
+}
+
+fn window(id: &str, position: [f32; 2], size: [f32; 2], collapsed: bool) -> ToolWindowLayout {
+    ToolWindowLayout {
+        id: Id::new(id),
+        position: position.into(),
+        size: size.into(),
+        collapsed,
+        minimized: false,
+        maximized: false,
+    }
+}
+
+fn ids(ids: &[&str]) -> Vec<Id> {
+    ids.iter().map(Id::new).collect()
+}
+
+#[test]
+fn apply_layout_restores_geometry_collapsed_state_and_stacking() {
+    let mut harness = Harness::new();
+    harness.run(vec![], |ui| show(ui, false));
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+
+    memory.apply_layout(&ToolWindowsLayout {
+        name: "Setup".to_owned(),
+        windows: vec![
+            window("c", [300.0, 300.0], [200.0, 150.0], false),
+            window("a", [100.0, 400.0], [250.0, 120.0], true),
+            window("b", [600.0, 100.0], [180.0, 300.0], false),
+        ],
+    });
+    let (response, _) = harness.run(vec![], |ui| show(ui, false));
+
+    assert_eq!(memory.position(Id::new("a")), Some(pos2(100.0, 400.0)));
+    assert_eq!(memory.size(Id::new("a")), Some(vec2(250.0, 120.0)));
+    assert_eq!(memory.position(Id::new("b")), Some(pos2(600.0, 100.0)));
+    assert_eq!(memory.size(Id::new("b")), Some(vec2(180.0, 300.0)));
+    assert_eq!(memory.position(Id::new("c")), Some(pos2(300.0, 300.0)));
+    assert_eq!(memory.size(Id::new("c")), Some(vec2(200.0, 150.0)));
+    let collapsed: Vec<(Id, bool)> = response
+        .windows
+        .iter()
+        .map(|window| (window.id, window.collapsed))
+        .collect();
+    assert_eq!(collapsed, vec![
+        (Id::new("c"), false),
+        (Id::new("a"), true),
+        (Id::new("b"), false)
+    ]);
+}
+
+#[test]
+fn apply_layout_keeps_windows_that_havent_been_shown_for_when_they_are() {
+    let mut harness = Harness::new();
+    harness.run(vec![], |ui| show(ui, false));
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+
+    memory.apply_layout(&ToolWindowsLayout {
+        name: "Setup".to_owned(),
+        windows: vec![
+            window("later", [500.0, 500.0], [220.0, 160.0], false),
+            window("a", [100.0, 400.0], [250.0, 120.0], false),
+        ],
+    });
+    assert_eq!(memory.window_ids(), ids(&["b", "c", "a"]));
+    harness.run(vec![], |ui| show(ui, false));
+    assert_eq!(memory.window_ids(), ids(&["b", "c", "a"]));
+
+    let (response, _) = harness.run(vec![], |ui| show(ui, true));
+    assert_eq!(memory.window_ids(), ids(&["b", "c", "a", "later"]));
+    let later = response.windows.last().unwrap();
+    assert_eq!(later.id, Id::new("later"));
+    assert_eq!(memory.position(Id::new("later")), Some(pos2(500.0, 500.0)));
+    assert_eq!(memory.size(Id::new("later")), Some(vec2(220.0, 160.0)));
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Id, Rect, Ui, pos2, vec2};
+use egui_tool_windows::{ToolWindowAction, ToolWindowResponse, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn window_id() -> Id {
+    Id::new("window")
+}
+
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            builder
+                .add_window(window_id())
+                .default_pos([200.0, 200.0])
+                .default_size([300.0, 200.0])
+                .maximizable(true)
+                .show("Window", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+fn window(response: &ToolWindowsResponse) -> &ToolWindowResponse {
+    &response.windows[0]
+}
+
+/// The window's rect before and after it's maximized, by clicking its maximize button.
+fn maximize(harness: &mut Harness) -> (Rect, Rect) {
+    let (response, _) = harness.run(vec![], show);
+    let rect = window(&response).rect;
+
+    let maximize = harness.find(Role::Button, "Maximize", show);
+    let maximized = harness.click(maximize, show);
+    assert!(
+        window(&maximized)
+            .actions
+            .contains(&ToolWindowAction::Maximized)
+    );
+    let (response, _) = harness.run(vec![], show);
+    assert!(window(&response).maximized);
+    (rect, window(&response).rect)
+}
+
+fn assert_fills_the_container(harness: &Harness, rect: Rect) {
+    let screen = harness.ctx.content_rect();
+    assert!(rect.width() > screen.width() - 20.0, "{rect:?}");
+    assert!(rect.height() > screen.height() - 20.0, "{rect:?}");
+}
+
+#[test]
+fn maximize_button_fills_the_container_and_restore_puts_it_back() {
+    let mut harness = Harness::with_accesskit(show);
+    let (rect, maximized_rect) = maximize(&mut harness);
+    assert_fills_the_container(&harness, maximized_rect);
+
+    let restore = harness.find(Role::Button, "Restore", show);
+    let restored = harness.click(restore, show);
+    assert!(
+        window(&restored)
+            .actions
+            .contains(&ToolWindowAction::Unmaximized)
+    );
+
+    let (response, _) = harness.run(vec![], show);
+    assert!(!window(&response).maximized);
+    assert_eq!(window(&response).rect, rect);
+}
+
+#[test]
+fn double_clicking_the_title_bar_maximizes_and_restores() {
+    let mut harness = Harness::new();
+    let (response, _) = harness.run(vec![], show);
+    let rect = window(&response).rect;
+    let title_bar = |rect: Rect| rect.center_top() + vec2(0.0, 10.0);
+
+    let maximized = harness.double_click(title_bar(rect), show);
+    assert!(
+        window(&maximized)
+            .actions
+            .contains(&ToolWindowAction::Maximized)
+    );
+    let (response, _) = harness.run(vec![], show);
+    assert!(window(&response).maximized);
+    assert_fills_the_container(&harness, window(&response).rect);
+
+    let restored = harness.double_click(title_bar(window(&response).rect), show);
+    assert!(
+        window(&restored)
+            .actions
+            .contains(&ToolWindowAction::Unmaximized)
+    );
+    let (response, _) = harness.run(vec![], show);
+    assert!(!window(&response).maximized);
+    assert_eq!(window(&response).rect, rect);
+}
+
+#[test]
+fn the_position_and_size_from_before_maximizing_are_kept() {
+    let mut harness = Harness::with_accesskit(show);
+    maximize(&mut harness);
+
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+    assert_eq!(memory.is_maximized(window_id()), Some(true));
+    assert_eq!(memory.position(window_id()), Some(pos2(200.0, 200.0)));
+    assert_eq!(memory.size(window_id()), Some(vec2(300.0, 200.0)));
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn restoring_after_a_restart_puts_the_window_back() {
+    let mut harness = Harness::with_accesskit(show);
+    let (rect, _) = maximize(&mut harness);
+    let stored = harness
+        .ctx
+        .memory(ron::to_string)
+        .unwrap();
+
+    let mut restarted = Harness::new();
+    restarted
+        .ctx
+        .memory_mut(|memory| *memory = ron::from_str(&stored).unwrap());
+    restarted.ctx.enable_accesskit();
+    let (response, _) = restarted.run(vec![], show);
+    assert!(window(&response).maximized);
+
+    let restore = restarted.find(Role::Button, "Restore", show);
+    restarted.click(restore, show);
+    let (response, _) = restarted.run(vec![], show);
+    assert!(!window(&response).maximized);
+    assert_eq!(window(&response).rect, rect);
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Ui, pos2, vec2};
+use egui_tool_windows::{ToolWindowResponse, ToolWindows, ToolWindowsMemory, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn first_id() -> Id {
+    Id::new("First")
+}
+
+fn second_id() -> Id {
+    Id::new("Second")
+}
+
+/// Two windows side by side.
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            for (id, x) in [(first_id(), 50.0), (second_id(), 450.0)] {
+                builder
+                    .add_window(id)
+                    .default_pos([x, 50.0])
+                    .default_size([300.0, 200.0])
+                    .show("Window", |ui| {
+                        ui.label("content");
+                    });
+            }
+        })
+}
+
+/// A harness that's shown the windows once, so they have state to change, and the memory of
+/// their container.
+fn shown() -> (Harness, ToolWindowsMemory) {
+    let mut harness = Harness::new();
+    harness.run(vec![], show);
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+    (harness, memory)
+}
+
+fn first(response: &ToolWindowsResponse) -> &ToolWindowResponse {
+    response
+        .windows
+        .iter()
+        .find(|window| window.id == first_id())
+        .unwrap()
+}
+
+#[test]
+fn set_position_moves_the_window() {
+    let (mut harness, memory) = shown();
+    let (before, _) = harness.run(vec![], show);
+
+    memory.set_position(first_id(), pos2(120.0, 300.0));
+    let (response, _) = harness.run(vec![], show);
+
+    assert_eq!(memory.position(first_id()), Some(pos2(120.0, 300.0)));
+    assert_eq!(first(&response).rect.min - first(&before).rect.min, vec2(70.0, 250.0));
+    assert_eq!(first(&response).rect.size(), first(&before).rect.size());
+}
+
+#[test]
+fn set_size_resizes_the_window() {
+    let (mut harness, memory) = shown();
+    let (before, _) = harness.run(vec![], show);
+
+    memory.set_size(first_id(), vec2(250.0, 320.0));
+    let (response, _) = harness.run(vec![], show);
+
+    assert_eq!(memory.size(first_id()), Some(vec2(250.0, 320.0)));
+    assert_eq!(
+        first(&response).rect.size() - first(&before).rect.size(),
+        vec2(-50.0, 120.0)
+    );
+    assert_eq!(first(&response).rect.min, first(&before).rect.min);
+}
+
+#[test]
+fn set_collapsed_collapses_and_expands_the_window() {
+    let (mut harness, memory) = shown();
+    let (before, _) = harness.run(vec![], show);
+
+    memory.set_collapsed(first_id(), true);
+    let (collapsed, _) = harness.run(vec![], show);
+    assert_eq!(memory.is_collapsed(first_id()), Some(true));
+    assert!(first(&collapsed).collapsed);
+    assert!(first(&collapsed).rect.height() < first(&before).rect.height());
+
+    memory.set_collapsed(first_id(), false);
+    let (expanded, _) = harness.run(vec![], show);
+    assert_eq!(memory.is_collapsed(first_id()), Some(false));
+    assert!(!first(&expanded).collapsed);
+    assert_eq!(first(&expanded).rect, first(&before).rect);
+}
+
+#[test]
+fn send_to_back_stacks_the_window_below_the_others() {
+    let (mut harness, memory) = shown();
+
+    memory.send_to_back(second_id());
+    let (response, _) = harness.run(vec![], show);
+
+    assert_eq!(memory.window_ids(), vec![second_id(), first_id()]);
+    let rendering_order: Vec<Id> = response
+        .windows
+        .iter()
+        .map(|window| window.id)
+        .collect();
+    assert_eq!(rendering_order, vec![second_id(), first_id()]);
+}
+
+#[test]
+fn reset_to_default_puts_the_window_back_to_its_defaults() {
+    let (mut harness, memory) = shown();
+    let (before, _) = harness.run(vec![], show);
+    memory.set_position(first_id(), pos2(120.0, 300.0));
+    memory.set_size(first_id(), vec2(250.0, 320.0));
+    memory.set_collapsed(first_id(), true);
+    harness.run(vec![], show);
+
+    memory.reset_to_default(first_id());
+    let (response, _) = harness.run(vec![], show);
+
+    assert_eq!(memory.position(first_id()), Some(pos2(50.0, 50.0)));
+    assert_eq!(memory.size(first_id()), Some(vec2(300.0, 200.0)));
+    assert_eq!(memory.is_collapsed(first_id()), Some(false));
+    assert_eq!(first(&response).rect, first(&before).rect);
+}
+
+#[test]
+fn changes_to_windows_that_havent_been_shown_are_ignored() {
+    let harness = Harness::new();
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+
+    memory.set_position(first_id(), pos2(120.0, 300.0));
+
+    assert_eq!(memory.position(first_id()), None);
+}
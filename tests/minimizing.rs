@@ -0,0 +1,109 @@
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Id, Rect, Ui, pos2, vec2};
+use egui_tool_windows::{ToolWindowAction, ToolWindowResponse, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+/// Two windows side by side, only the first of which can be minimized.
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .windows(ui, |builder| {
+            for (index, title) in ["First", "Second"]
+                .into_iter()
+                .enumerate()
+            {
+                builder
+                    .add_window(Id::new(title))
+                    .default_pos([50.0 + 400.0 * index as f32, 50.0])
+                    .default_size([300.0, 200.0])
+                    .minimizable(index == 0)
+                    .show(title, |ui| {
+                        ui.label("content");
+                    });
+            }
+        })
+}
+
+fn first(response: &ToolWindowsResponse) -> &ToolWindowResponse {
+    response
+        .windows
+        .iter()
+        .find(|window| window.id == Id::new("First"))
+        .expect("the first window is shown, either itself or on the shelf")
+}
+
+#[test]
+fn minimizing_puts_the_window_on_the_shelf_and_restoring_puts_it_back() {
+    let mut harness = Harness::with_accesskit(show);
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+    memory.set_position(Id::new("First"), pos2(120.0, 240.0));
+    memory.set_size(Id::new("First"), vec2(260.0, 180.0));
+    let (response, _) = harness.run(vec![], show);
+    let rect = first(&response).rect;
+
+    let minimize = harness.find(Role::Button, "Minimize", show);
+    let minimized = harness.click(minimize, show);
+    assert!(
+        first(&minimized)
+            .actions
+            .contains(&ToolWindowAction::Minimized)
+    );
+
+    let (response, _) = harness.run(vec![], show);
+    let shelf_button = first(&response);
+    assert!(shelf_button.minimized);
+    // Along the bottom of the container, out of the way of the other window.
+    assert!(shelf_button.rect.bottom() > 750.0, "{:?}", shelf_button.rect);
+    assert_eq!(memory.is_minimized(Id::new("First")), Some(true));
+
+    let restored = harness.click(shelf_button.rect.center(), show);
+    assert!(
+        first(&restored)
+            .actions
+            .contains(&ToolWindowAction::Restored)
+    );
+
+    let (response, _) = harness.run(vec![], show);
+    assert!(!first(&response).minimized);
+    assert_eq!(first(&response).rect, rect);
+    assert_eq!(memory.position(Id::new("First")), Some(pos2(120.0, 240.0)));
+    assert_eq!(memory.size(Id::new("First")), Some(vec2(260.0, 180.0)));
+    // On top of the window that was left.
+    assert_eq!(response.windows.last().unwrap().id, Id::new("First"));
+}
+
+#[test]
+fn dropping_onto_a_minimized_windows_old_place_doesnt_group() {
+    let mut harness = Harness::new();
+    let (response, _) = harness.run(vec![], show);
+    let first = response.windows[0].rect;
+    let second = response.windows[1].rect;
+
+    ToolWindows::memory(&harness.ctx, container_id()).set_minimized(Id::new("First"), true);
+    harness.run(vec![], show);
+
+    let title_bar = |rect: Rect| rect.center_top() + vec2(0.0, 10.0);
+    let dropped = harness.drag(title_bar(second), title_bar(first), show);
+    assert!(
+        !dropped
+            .actions()
+            .any(|(_, action)| action == ToolWindowAction::Grouped),
+        "grouped with a minimized window"
+    );
+    let (response, _) = harness.run(vec![], show);
+
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+    assert_eq!(memory.is_minimized(Id::new("Second")), Some(false));
+    let second = response
+        .windows
+        .iter()
+        .find(|window| window.id == Id::new("Second"))
+        .expect("the dragged window is still shown");
+    assert!(!second.minimized);
+}
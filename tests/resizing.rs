@@ -0,0 +1,197 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Pos2, Rect, Ui, Vec2, vec2};
+use egui_tool_windows::{ToolWindowAction, ToolWindows, ToolWindowsResponse};
+
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(Id::new("container"))
+        .windows(ui, |builder| {
+            builder
+                .add_window(Id::new("window"))
+                .default_pos([200.0, 200.0])
+                .default_size([300.0, 200.0])
+                .show("Window", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+/// A window that can't be resized or moved by the user.
+fn show_locked(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(Id::new("container"))
+        .windows(ui, |builder| {
+            builder
+                .add_window(Id::new("window"))
+                .default_pos([200.0, 200.0])
+                .default_size([300.0, 200.0])
+                .resizable(false)
+                .movable(false)
+                .show("Window", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+/// A window with a fixed size, which wins over being made resizable afterwards.
+fn show_fixed_size(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(Id::new("container"))
+        .windows(ui, |builder| {
+            builder
+                .add_window(Id::new("window"))
+                .default_pos([200.0, 200.0])
+                .fixed_size([300.0, 200.0])
+                .resizable(true)
+                .show("Window", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+/// Drags from `from`, given the window's rect, by `delta`, returning the actions of every frame of
+/// the drag along with the window's size change.
+fn drag_from(from: impl FnOnce(Rect) -> Pos2, delta: Vec2) -> (Vec<ToolWindowAction>, Vec2) {
+    let (actions, before, after) = drag_window(show, from, delta);
+    (actions, after.size() - before.size())
+}
+
+/// Drags from `from`, given the window's rect, by `delta`, returning the actions of every frame of
+/// the drag along with the window's rect before and after.
+fn drag_window(
+    mut show: impl FnMut(&mut Ui) -> ToolWindowsResponse,
+    from: impl FnOnce(Rect) -> Pos2,
+    delta: Vec2,
+) -> (Vec<ToolWindowAction>, Rect, Rect) {
+    let mut harness = Harness::new();
+    let (response, _) = harness.run(vec![], &mut show);
+    let rect = response.windows[0].rect;
+
+    let from = from(rect);
+    let mut actions = vec![];
+    let response = harness.drag(from, from + delta, |ui| {
+        let response = show(ui);
+        actions.extend(
+            response.windows[0]
+                .actions
+                .iter()
+                .copied(),
+        );
+        response
+    });
+    (actions, rect, response.windows[0].rect)
+}
+
+fn is_resized(action: &ToolWindowAction) -> bool {
+    matches!(action, ToolWindowAction::Resized { .. })
+}
+
+fn assert_resized(actions: &[ToolWindowAction], size_change: Vec2, expected: Vec2) {
+    assert!(actions.iter().any(is_resized), "not resized: {actions:?}");
+    assert_eq!(size_change, expected);
+}
+
+#[test]
+fn pressing_just_inside_the_top_left_corner_resizes() {
+    let (actions, size_change) = drag_from(|rect| rect.left_top() + vec2(2.0, 2.0), vec2(-30.0, -20.0));
+
+    assert_resized(&actions, size_change, vec2(30.0, 20.0));
+}
+
+#[test]
+fn pressing_just_inside_the_top_right_corner_resizes() {
+    let (actions, size_change) = drag_from(|rect| rect.right_top() + vec2(-2.0, 2.0), vec2(30.0, -20.0));
+
+    assert_resized(&actions, size_change, vec2(30.0, 20.0));
+}
+
+#[test]
+fn pressing_just_inside_the_top_edge_resizes() {
+    let (actions, size_change) = drag_from(|rect| rect.center_top() + vec2(0.0, 2.0), vec2(0.0, -20.0));
+
+    assert_resized(&actions, size_change, vec2(0.0, 20.0));
+}
+
+#[test]
+fn pressing_below_the_top_edge_moves() {
+    let (actions, size_change) = drag_from(|rect| rect.center_top() + vec2(0.0, 10.0), vec2(30.0, 20.0));
+
+    assert!(
+        actions
+            .iter()
+            .any(|action| matches!(action, ToolWindowAction::Moved { .. })),
+        "not moved: {actions:?}"
+    );
+    assert!(!actions.iter().any(is_resized), "resized: {actions:?}");
+    assert_eq!(size_change, Vec2::ZERO);
+}
+
+#[test]
+fn resizing_reports_the_drag_starting_and_stopping() {
+    let (actions, _) = drag_from(|rect| rect.right_bottom() - vec2(2.0, 2.0), vec2(30.0, 20.0));
+
+    let started = actions
+        .iter()
+        .filter(|action| **action == ToolWindowAction::DragStarted)
+        .count();
+    assert_eq!(started, 1, "{actions:?}");
+    let stopped = actions
+        .iter()
+        .position(|action| *action == ToolWindowAction::DragStopped)
+        .unwrap_or_else(|| panic!("drag never stopped: {actions:?}"));
+    let resized = actions
+        .iter()
+        .position(is_resized)
+        .unwrap_or_else(|| panic!("not resized: {actions:?}"));
+    assert!(stopped < resized, "{actions:?}");
+}
+
+/// Where to press, given the window's rect.
+type GrabPoint = fn(Rect) -> Pos2;
+
+#[test]
+fn a_window_that_cant_be_resized_or_moved_ignores_drags() {
+    let grab_points: [(&str, GrabPoint); 9] = [
+        ("left edge", |rect| rect.left_center() + vec2(2.0, 0.0)),
+        ("right edge", |rect| rect.right_center() - vec2(2.0, 0.0)),
+        ("top edge", |rect| rect.center_top() + vec2(0.0, 2.0)),
+        ("bottom edge", |rect| rect.center_bottom() - vec2(0.0, 2.0)),
+        ("top-left corner", |rect| rect.left_top() + vec2(2.0, 2.0)),
+        ("top-right corner", |rect| rect.right_top() + vec2(-2.0, 2.0)),
+        ("bottom-left corner", |rect| rect.left_bottom() + vec2(2.0, -2.0)),
+        ("bottom-right corner", |rect| rect.right_bottom() - vec2(2.0, 2.0)),
+        ("title bar", |rect| rect.center_top() + vec2(0.0, 10.0)),
+    ];
+
+    for (name, from) in grab_points {
+        let (actions, before, after) = drag_window(show_locked, from, vec2(30.0, 20.0));
+
+        assert_eq!(after, before, "dragging the {name} changed the window");
+        assert!(
+            !actions.iter().any(|action| matches!(
+                action,
+                ToolWindowAction::Resized { .. } | ToolWindowAction::Moved { .. } | ToolWindowAction::DragStarted
+            )),
+            "dragging the {name}: {actions:?}"
+        );
+    }
+}
+
+#[test]
+fn a_fixed_size_window_cant_be_resized_even_if_made_resizable_afterwards() {
+    let (actions, before, after) = drag_window(
+        show_fixed_size,
+        |rect| rect.right_bottom() - vec2(2.0, 2.0),
+        vec2(30.0, 20.0),
+    );
+
+    assert_eq!(after, before);
+    assert!(
+        !actions
+            .iter()
+            .any(|action| is_resized(action) || *action == ToolWindowAction::DragStarted),
+        "{actions:?}"
+    );
+}
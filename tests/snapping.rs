@@ -0,0 +1,52 @@
+mod common;
+
+use common::Harness;
+use egui::{Id, Modifiers, Pos2, Ui, pos2, vec2};
+use egui_tool_windows::{Snapping, ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn window_id() -> Id {
+    Id::new("window")
+}
+
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::new()
+        .id(container_id())
+        .snapping(Snapping::default())
+        .windows(ui, |builder| {
+            builder
+                .add_window(window_id())
+                .default_pos([200.0, 200.0])
+                .default_size([300.0, 200.0])
+                .show("Window", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+/// Drags the window's title bar to leave its left edge just short of the container's, holding
+/// `modifiers`, returning where the window ends up.
+fn drag_towards_the_left_edge(modifiers: Modifiers) -> Pos2 {
+    let mut harness = Harness::new();
+    let (response, _) = harness.run(vec![], show);
+    let title_bar = response.windows[0].rect.center_top() + vec2(0.0, 10.0);
+
+    harness.drag_holding(modifiers, title_bar, title_bar - vec2(196.0, 0.0), show);
+    harness.run(vec![], show);
+    ToolWindows::memory(&harness.ctx, container_id())
+        .position(window_id())
+        .unwrap()
+}
+
+#[test]
+fn dragging_near_the_containers_edge_snaps_to_it() {
+    assert_eq!(drag_towards_the_left_edge(Modifiers::NONE), pos2(0.0, 200.0));
+}
+
+#[test]
+fn holding_the_bypass_modifiers_doesnt_snap() {
+    assert_eq!(drag_towards_the_left_edge(Modifiers::ALT), pos2(4.0, 200.0));
+}
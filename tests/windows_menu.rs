@@ -0,0 +1,97 @@
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Id, Ui};
+use egui_tool_windows::{ToolWindows, ToolWindowsResponse};
+
+fn container_id() -> Id {
+    Id::new("container")
+}
+
+fn tools_id() -> Id {
+    Id::new("tools")
+}
+
+/// The container's windows menu, above a container with a closable window.
+fn show(ui: &mut Ui) -> ToolWindowsResponse {
+    ToolWindows::memory(ui.ctx(), container_id()).windows_menu_ui(ui);
+
+    ToolWindows::new()
+        .id(container_id())
+        .auto_close(true)
+        .windows(ui, |builder| {
+            builder
+                .add_window(tools_id())
+                .default_pos([400.0, 300.0])
+                .default_size([300.0, 200.0])
+                .closable(true)
+                .show("Tools", |ui| {
+                    ui.label("content");
+                });
+            builder
+                .add_window(Id::new("fixed"))
+                .default_pos([400.0, 550.0])
+                .default_size([300.0, 100.0])
+                .show("Fixed", |ui| {
+                    ui.label("content");
+                });
+        })
+}
+
+fn is_shown(response: &ToolWindowsResponse, id: Id) -> bool {
+    response
+        .windows
+        .iter()
+        .any(|window| window.id == id)
+}
+
+#[test]
+fn close_button_closes_the_window() {
+    let mut harness = Harness::with_accesskit(show);
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+    assert_eq!(memory.is_open(tools_id()), Some(true));
+
+    let close = harness.find(Role::Button, "Close", show);
+    harness.click(close, show);
+    let (response, _) = harness.run(vec![], show);
+
+    assert!(!is_shown(&response, tools_id()));
+    assert!(is_shown(&response, Id::new("fixed")));
+    assert_eq!(memory.is_open(tools_id()), Some(false));
+    let known: Vec<(Id, bool)> = memory
+        .known_windows()
+        .iter()
+        .map(|window| (window.id, window.open))
+        .collect();
+    assert_eq!(known, vec![(tools_id(), false), (Id::new("fixed"), true)]);
+}
+
+#[test]
+fn windows_menu_reopens_a_closed_window() {
+    let mut harness = Harness::with_accesskit(show);
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+
+    let close = harness.find(Role::Button, "Close", show);
+    harness.click(close, show);
+
+    let checkbox = harness.find(Role::CheckBox, "Tools", show);
+    harness.click(checkbox, show);
+    let (response, _) = harness.run(vec![], show);
+
+    assert!(is_shown(&response, tools_id()));
+    assert_eq!(memory.is_open(tools_id()), Some(true));
+}
+
+#[test]
+fn windows_menu_cant_close_a_window_that_isnt_closable() {
+    let mut harness = Harness::with_accesskit(show);
+    let memory = ToolWindows::memory(&harness.ctx, container_id());
+
+    let checkbox = harness.find(Role::CheckBox, "Fixed", show);
+    harness.click(checkbox, show);
+    let (response, _) = harness.run(vec![], show);
+
+    assert!(is_shown(&response, Id::new("fixed")));
+    assert_eq!(memory.is_open(Id::new("fixed")), Some(true));
+}